@@ -13,6 +13,10 @@ func _on_visibility_changed() -> void:
 func _on_exit_pressed() -> void:
 	main_menu.swap_to_main_menu()
 
+## When the add bot button is pressed, add a bot to the next empty slot
+func _on_add_bot_pressed() -> void:
+	game.add_bot("normal")
+
 ## When the start game button is pressed, start the game
 func _on_start_game_pressed() -> void:
 	if game.get_team_game():
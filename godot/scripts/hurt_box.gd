@@ -15,12 +15,5 @@ func _on_area_entered(area: Area2D):
 		var attacker = area.get_parent() as Player
 		# Make sure we don't damage ourselves
 		if attacker != parent_player:
-			# Deal damage
-			var damage = -45.0
-			if parent_player.is_burning_metal_from_string("pewter"):
-				damage = damage * 1.35
-			
-			parent_player.adjust_health(damage)
-			if parent_player.get_health() <= 0:
-				# increment the elims of the attacking player
-				area.get_parent().increment_eliminations(attacker.get_player_id())
+			# Damage, modifiers and eliminations are resolved by the player
+			parent_player.receive_melee_hit(attacker)
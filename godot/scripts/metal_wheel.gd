@@ -51,9 +51,8 @@ func _process(delta: float) -> void:
 		else: 
 			# Check if we need to add the new metal or not
 			if metal_reserve_bar_manager.add_remove(prev_metal, new_metal):
-				# Change the keybindings 
-				InputMap.action_erase_event(prev_metal, rebind_event)
-				InputMap.action_add_event(new_metal, rebind_event)
+				# Change the keybindings for this player only
+				input_manager.swap_action_binding(new_metal, rebind_event)
 				display_msg(prev_metal + " changed to " + new_metal)
 				
 			else: # If the new metal was already on screen 
@@ -96,8 +95,9 @@ func _input(event: InputEvent) -> void:
 ##
 ## @param `event` (InputEvent) - The input event to get the action name of
 func get_action_from_event(event: InputEvent):
-	for action in metals:
-		if InputMap.event_is_action(event, action, true):
-			rebind_event = event
-			return action
+	# Ask the input manager so buttons this player rebound are found
+	var action = input_manager.get_event_action(event)
+	if action in metals:
+		rebind_event = event
+		return action
 	return "Not a metal"
@@ -7,7 +7,16 @@
 //! Author: Charles Barth, Michael Imerman, Trinity Pittman
 //! Version: Spring 2025
 use crate::{
-    main_menu::MainMenu, map::Map, player::player::Player, settings::Settings,
+    main_menu::MainMenu,
+    map::Map,
+    player::{
+        bot_controller::{BotController, FIRST_BOT_DEVICE_ID},
+        enums::bot_difficulty::BotDifficulty,
+        keyboard_profile::KeyboardProfile,
+        player::Player,
+    },
+    player_stats::PlayerStats,
+    settings::Settings,
     split_screen::SplitScreen,
 };
 
@@ -29,6 +38,8 @@ static mut GAME_MODE: Option<String> = None;
 const REQUIRED_ELIMINATIONS: i32 = 5;
 // The number of rounds required to win the game
 const REQUIRED_ROUNDS: i32 = 1;
+// The number of players a game can hold, one for each spawn point
+const MAX_PLAYERS: usize = 4;
 
 /// The Game class is responsible for managing the game state such as players,
 /// maps, and the main menu.
@@ -44,6 +55,8 @@ pub struct Game {
     team_tracker: HashMap<String, Vec<i32>>,
     /// A list of connected input devices.
     devices: Vec<i32>,
+    /// The difficulty of each bot, keyed by the device id the bot was given.
+    bots: HashMap<i32, BotDifficulty>,
     /// The name of the button that players must press to register.
     register_button: StringName,
     /// The scene for the player node.
@@ -75,8 +88,8 @@ pub struct Game {
     screen_size: Vector2,
     /// The settings for the game
     settings: Gd<Settings>,
-    /// The number of kills for each player
-    eliminations: HashMap<i32, i32>,
+    /// The stats of each player, such as their eliminations and assists
+    stats: HashMap<i32, PlayerStats>,
     /// The number of round wins each player has
     round_wins: HashMap<i32, i32>,
     /// A flag to determine if a new round should be started
@@ -85,6 +98,9 @@ pub struct Game {
     round_transition_timer: Gd<Timer>,
     /// Label to display the winner message
     winner_label: Option<Gd<Label>>,
+    /// The active copperclouds keyed by the id of the player burning copper.
+    /// Each entry holds the burning player and the radius of their cloud.
+    copper_clouds: HashMap<i32, (Gd<Player>, f32)>,
 }
 
 #[godot_api]
@@ -120,6 +136,7 @@ impl INode2D for Game {
             players: Vec::new(),
             team_tracker: HashMap::new(),
             devices: Vec::new(),
+            bots: HashMap::new(),
             register_button: "jump".into(),
             player_scene: load::<PackedScene>("res://scenes/player.tscn"),
             current_player_id: 0,
@@ -136,11 +153,12 @@ impl INode2D for Game {
             day_night_timer,
             screen_size: Vector2::new(screen_size.x as f32, screen_size.y as f32),
             settings,
-            eliminations: HashMap::new(),
+            stats: HashMap::new(),
             round_wins: HashMap::new(),
             should_start_new_round: false,
             round_transition_timer,
             winner_label: None,
+            copper_clouds: HashMap::new(),
         }
     }
 
@@ -148,6 +166,8 @@ impl INode2D for Game {
     /// to the scene tree.
     /// This is where the signals are connected and the maps are loaded.
     fn ready(&mut self) {
+        KeyboardProfile::register_actions();
+
         Input::singleton().connect(
             "joy_connection_changed",
             &Callable::from_object_method(
@@ -207,7 +227,7 @@ impl INode2D for Game {
     /// # Arguments:
     /// * `event` - The input event that triggered this method.
     fn input(&mut self, event: Gd<InputEvent>) {
-        let device_id = event.get_device();
+        let device_id = KeyboardProfile::event_device(&event);
 
         let input_map = InputMap::singleton();
         let register_button = self.register_button.clone();
@@ -275,17 +295,203 @@ impl Game {
         let mut main_menu = self.get_main_menu();
         main_menu.bind_mut().add_player(self.current_player_id);
 
-        // Add the player's ID and eliminations to the hashmap
-        self.eliminations.insert(self.current_player_id, 0);
+        // Add the player's ID and stats to the hashmap
+        self.stats
+            .insert(self.current_player_id, PlayerStats::default());
+    }
+
+    /// This will add a bot to fill an empty slot. Bots are registered like any other player
+    /// but are given a device id that no real device uses.
+    ///
+    /// # Arguments:
+    /// * `difficulty` - "easy", "normal" or "hard".
+    #[func]
+    pub fn add_bot(&mut self, difficulty: String) {
+        let Some(difficulty) = BotDifficulty::from_string(&difficulty) else {
+            godot_error!("{} is not a bot difficulty", difficulty);
+            return;
+        };
+        if self.started || self.players.len() >= MAX_PLAYERS {
+            self.get_main_menu()
+                .bind()
+                .add_notification("There are no empty slots for a bot".to_string());
+            return;
+        }
+
+        let device_id = (0..)
+            .map(|index| FIRST_BOT_DEVICE_ID - index)
+            .find(|device_id| !self.devices.contains(device_id))
+            .expect("Ran out of bot device ids");
+        self.bots.insert(device_id, difficulty);
+        self.register_player(device_id);
+    }
+
+    /// This will add bots until every slot in the game is filled.
+    ///
+    /// # Arguments:
+    /// * `difficulty` - "easy", "normal" or "hard".
+    #[func]
+    pub fn fill_with_bots(&mut self, difficulty: String) {
+        if BotDifficulty::from_string(&difficulty).is_none() {
+            godot_error!("{} is not a bot difficulty", difficulty);
+            return;
+        }
+
+        while !self.started && self.players.len() < MAX_PLAYERS {
+            self.add_bot(difficulty.clone());
+        }
+    }
+
+    /// This will remove every bot that has been added.
+    #[func]
+    pub fn remove_bots(&mut self) {
+        let bot_devices: Vec<i32> = self.bots.keys().copied().collect();
+        for device_id in bot_devices {
+            self.disconnect_player(device_id);
+        }
+    }
+
+    /// This will give a player a bot to control them if their device id belongs to a bot.
+    /// The bot takes over the player once the player is added to the scene tree. A player that
+    /// already has a bot keeps it.
+    ///
+    /// # Arguments:
+    /// * `player` - The player to give a bot.
+    /// * `device_id` - The device id of the player.
+    fn attach_bot(&self, player: &mut Gd<Player>, device_id: i32) {
+        let Some(&difficulty) = self.bots.get(&device_id) else {
+            return;
+        };
+        if player.has_node("BotController") {
+            return;
+        }
+
+        let mut bot = BotController::new_alloc();
+        bot.set_name("BotController");
+        bot.bind_mut().set_difficulty(difficulty);
+        player.add_child(&bot);
+    }
+
+    /// Records an elimination, crediting the killer and any assisting players.
+    ///
+    /// # Arguments:
+    /// * `killer_id` - The id of the player that landed the killing blow.
+    /// * `assist_ids` - The ids of the players that helped with the elimination.
+    /// * `victim_id` - The id of the player that was eliminated.
+    pub fn record_elimination(&mut self, killer_id: i32, assist_ids: &[i32], victim_id: i32) {
+        self.stats.entry(killer_id).or_default().eliminations += 1;
+
+        for assist_id in assist_ids {
+            self.stats.entry(*assist_id).or_default().assists += 1;
+        }
+
+        self.record_death(victim_id);
+    }
+
+    /// Records the death of a player.
+    ///
+    /// # Arguments:
+    /// * `victim_id` - The id of the player that died.
+    pub fn record_death(&mut self, victim_id: i32) {
+        self.stats.entry(victim_id).or_default().deaths += 1;
+    }
+
+    /// Records damage dealt to a player.
+    ///
+    /// # Arguments:
+    /// * `source_id` - The id of the player that dealt the damage, if any.
+    /// * `target_id` - The id of the player that took the damage.
+    /// * `amount` - The amount of damage dealt.
+    pub fn record_damage(&mut self, source_id: Option<i32>, target_id: i32, amount: f64) {
+        if let Some(source_id) = source_id {
+            self.stats.entry(source_id).or_default().damage_dealt += amount;
+        }
+
+        self.stats.entry(target_id).or_default().damage_taken += amount;
+    }
+
+    /// Gets the stats of a player.
+    ///
+    /// # Arguments:
+    /// * `player_id` - The id of the player to get the stats of.
+    ///
+    /// # Returns
+    /// * `Dictionary` - The player's eliminations, assists, deaths, damage dealt and damage taken.
+    #[func]
+    pub fn get_player_stats(&self, player_id: i32) -> Dictionary {
+        self.stats
+            .get(&player_id)
+            .cloned()
+            .unwrap_or_default()
+            .to_dictionary()
+    }
+
+    /// Gets every player currently in the game.
+    ///
+    /// # Returns
+    /// * `Vec<Gd<Player>>` - The players in the game.
+    pub fn get_players(&self) -> Vec<Gd<Player>> {
+        self.players.clone()
+    }
+
+    /// Gets the player ids of every teammate of the given player.
+    /// Team ids are stored as the index of the player's device so they are converted to player
+    /// ids before being returned. Solo games have no teammates.
+    ///
+    /// # Arguments:
+    /// * `player_id` - The id of the player to find the teammates of.
+    ///
+    /// # Returns
+    /// * `Vec<i32>` - The player ids of the player's teammates.
+    pub fn get_teammate_ids(&self, player_id: i32) -> Vec<i32> {
+        if !self.settings.bind().get_team_game() {
+            return Vec::new();
+        }
+
+        let team_index = player_id - 1;
+        for team in self.team_tracker.values() {
+            if team.contains(&team_index) {
+                return team
+                    .iter()
+                    .filter(|&&id| id != team_index)
+                    .map(|id| id + 1)
+                    .collect();
+            }
+        }
+
+        Vec::new()
+    }
+
+    /// Updates the coppercloud of a player.
+    /// A radius of 0 or less removes the player's cloud.
+    ///
+    /// # Arguments:
+    /// * `player` - The player burning copper.
+    /// * `player_id` - The id of the player burning copper.
+    /// * `radius` - The radius of the player's cloud.
+    pub fn set_copper_cloud(&mut self, player: Gd<Player>, player_id: i32, radius: f32) {
+        if radius <= 0.0 {
+            self.copper_clouds.remove(&player_id);
+        } else {
+            self.copper_clouds.insert(player_id, (player, radius));
+        }
     }
 
-    /// Updates the number of elimination for a player as they get a new one.
+    /// Checks if something at the target position is hidden from an observer by a coppercloud.
+    /// A target is concealed when it is inside a cloud that the observer is outside of.
+    /// Observers inside the same cloud can still sense everything within it.
     ///
     /// # Arguments:
-    /// * `player_id` - The id of the player that got an elimination.
-    pub fn update_eliminations(&mut self, player_id: i32) {
-        let eliminations = self.eliminations.entry(player_id).or_insert(0);
-        *eliminations += 1;
+    /// * `target` - The global position of the player or pulse being sensed.
+    /// * `observer` - The global position of the player burning bronze.
+    ///
+    /// # Returns
+    /// * `bool` - True if the target is hidden from the observer.
+    pub fn is_concealed_from(&self, target: Vector2, observer: Vector2) -> bool {
+        self.copper_clouds.values().any(|(burner, radius)| {
+            let center = burner.get_global_position();
+            center.distance_to(target) <= *radius && center.distance_to(observer) > *radius
+        })
     }
 
     /// This will disconnect a player from the game.
@@ -309,6 +515,7 @@ impl Game {
 
         self.players.remove(index);
         self.devices.remove(index);
+        self.bots.remove(&device_id);
         self.current_player_id = self.devices.len() as i32;
     }
 
@@ -547,6 +754,9 @@ impl Game {
             let mut bound_player = player.bind_mut();
             bound_player.set_device_id(self.devices[index]);
             bound_player.set_player_id(player_id);
+            drop(bound_player);
+
+            self.attach_bot(player, self.devices[index]);
         }
 
         // If its a team game, set the players outline colors
@@ -720,6 +930,7 @@ impl Game {
     /// them.
     fn reset_players(&mut self) {
         self.players.clear();
+        self.copper_clouds.clear();
         self.current_player_id = 0;
         for device_id in self.devices.iter() {
             self.current_player_id += 1;
@@ -737,22 +948,12 @@ impl Game {
     /// If there is only one player left in the game they will be declared the
     /// winner.
     ///
+    /// Eliminations are credited as they happen by the DamageResolver so they
+    /// are not added again here.
+    ///
     /// # Arguments:
     /// * `player_id` - The id of the player to disconnect.
-    /// * `instance_elims` - The number of eliminations the player got in this
-    ///                      instance.
-    pub fn remove_player(&mut self, player_id: i32, instance_elims: i32) {
-        // before removing the player, update the eliminations for the player
-        // associated with the player_id in the hashmap
-
-        // get the number of eliminations for the player in the hashmap
-        let eliminations = self.eliminations.get(&player_id).unwrap();
-
-        // update the eliminations in the hashmap with the eliminations in this
-        // instance + the eliminations in the hashmap
-        self.eliminations
-            .insert(player_id, eliminations + instance_elims);
-
+    pub fn remove_player(&mut self, player_id: i32) {
         let mut index: usize = 999;
         for i in 0..self.players.len() {
             if self.players[i].bind().get_player_id() == player_id {
@@ -765,6 +966,8 @@ impl Game {
             player.queue_free();
         }
 
+        self.copper_clouds.remove(&player_id);
+
         let player_length = self.players.len();
 
         // if there is only one player left in the game, they are the winner of
@@ -799,7 +1002,6 @@ impl Game {
 
         godot_print!("Hashmap of wins {:?}", wins_check);
         godot_print!("Hashmap of teams {:?}", team);
-        // let elims = &self.eliminations;
         let mut team_elims: i32 = 0;
         for id in team.clone() {
             if wins_check.contains_key(&(id + 1)) {
@@ -824,7 +1026,11 @@ impl Game {
 
         if self.get_game_mode() == "Head Hunters" {
             end_condition = REQUIRED_ELIMINATIONS;
-            wins_check = self.eliminations.clone();
+            wins_check = self
+                .stats
+                .iter()
+                .map(|(player_id, stats)| (*player_id, stats.eliminations))
+                .collect();
         } else {
             // Last Player Standing
             end_condition = REQUIRED_ROUNDS;
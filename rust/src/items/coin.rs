@@ -4,11 +4,24 @@
 //! Author: Charles Barth
 //! Version: Spring 2025
 
-use crate::metal_object::MetalObject;
+use crate::metal_object::{MetalObject, RICOCHET_SPEED};
+use crate::player::damage_event::DamageEvent;
 use crate::player::enums::coin_events::CoinState;
+use crate::player::enums::damage_type::DamageType;
 use crate::player::player::Player;
 use godot::prelude::*;
 
+/// Converts the kinetic energy of a coin into the damage it deals
+const COIN_ENERGY_TO_DAMAGE: f64 = 0.0003;
+/// The least damage a coin can deal to the player it hits
+const COIN_MIN_DAMAGE: f64 = 1.0;
+/// The most damage a coin can deal to the player it hits
+const COIN_MAX_DAMAGE: f64 = 120.0;
+/// The number of players a thrown coin can hit before it stops
+const COIN_PIERCE_HITS: i32 = 2;
+/// The fraction of its speed a coin keeps after piercing through a player
+const PIERCE_SPEED_RETAINED: f32 = 0.6;
+
 #[derive(GodotClass)]
 #[class(base=Node2D)]
 /// Represents a coin
@@ -23,6 +36,10 @@ pub struct Coin {
     curr_player: Option<Gd<Player>>,
     /// The metal object associated with this coin 
     metal_object: Option<Gd<MetalObject>>,
+    /// The number of players the coin can still hit before it stops
+    hits_remaining: i32,
+    /// The players the coin has pierced through since it was thrown
+    pierced: Vec<Gd<Player>>,
 }
 
 #[godot_api]
@@ -42,6 +59,8 @@ impl INode2D for Coin {
             weight: 10,
             curr_player: None, // Initialy no player
             metal_object: None,
+            hits_remaining: COIN_PIERCE_HITS,
+            pierced: Vec::new(),
         }
     }
 
@@ -62,6 +81,29 @@ impl INode2D for Coin {
         // By default this is set to 0, if we want to record contacts it needs to be greater
         metal_object.set_max_contacts_reported(1);
     }
+
+    /// The Godot method called every physics frame.
+    ///
+    /// A thrown coin that has slowed down while resting against something that is not a player
+    /// can no longer do damage, so it goes back to idle to be picked up.
+    fn physics_process(&mut self, _delta: f64) {
+        if self.state != CoinState::Thrown {
+            return;
+        }
+
+        let metal_object = self.get_metal_obejct();
+        if metal_object.get_linear_velocity().length() > RICOCHET_SPEED {
+            return;
+        }
+
+        let resting = metal_object
+            .get_colliding_bodies()
+            .iter_shared()
+            .any(|body| body.try_cast::<Player>().is_err());
+        if resting {
+            self.settle();
+        }
+    }
 }
 
 #[godot_api]
@@ -96,6 +138,14 @@ impl Coin {
 
             // See if what entered the coin was a player
             if let Ok(mut player) = body.try_cast::<Player>() {
+                // A coin pushed into someone else hits them instead of being caught
+                if self.is_pushed_into(&player) {
+                    self.set_state(CoinState::Thrown);
+                    self.hits_remaining = COIN_PIERCE_HITS;
+                    self.hit_player(player);
+                    return;
+                }
+
                 // Update state
                 self.set_state(CoinState::PickedUp);
 
@@ -161,25 +211,139 @@ impl Coin {
 
             // Update state
             self.set_state(CoinState::Thrown);
+            self.hits_remaining = COIN_PIERCE_HITS;
         }
     }
 
     /// Handles dropping the coin, called when the coin enters something while
-    /// in throw state. If the coin enters a player other than the player who
-    /// threw the coin, damages the player.
+    /// in throw state. If the coin enters a player other than the one responsible for it,
+    /// damages the player. Coins moving fast enough to ricochet keep flying after hitting a wall.
     pub fn drop(&mut self, body: Gd<Node2D>) {
-        // If the player the coin entered is not the current player
-        if let Ok(mut player) = body.try_cast::<Player>() {
-            if player.get_name() != self.curr_player.as_ref().unwrap().get_name() {
-                // Hurt the player
-                player.bind_mut().adjust_health(-10.);
-                // change the state to idle
-                self.set_state(CoinState::Idle);
-            }
-        // If the player the coin entered is the current player
+        if let Ok(player) = body.try_cast::<Player>() {
+            self.hit_player(player);
+        } else if self.get_metal_obejct().get_linear_velocity().length() <= RICOCHET_SPEED {
+            self.settle();
+        }
+    }
+
+    /// Damages a player hit by the coin based on how fast and heavy the coin is.
+    /// If the coin has hits remaining it pierces through the player and keeps flying slower,
+    /// otherwise it goes idle. A player parrying sends the coin back the way it came.
+    ///
+    /// # Arguments
+    /// * `player` (`Gd<Player>`) - The player the coin hit
+    fn hit_player(&mut self, mut player: Gd<Player>) {
+        let source = self.get_source();
+        let is_source = source
+            .as_ref()
+            .is_some_and(|source| source.instance_id() == player.instance_id());
+        let already_pierced = self
+            .pierced
+            .iter()
+            .any(|pierced| pierced.instance_id() == player.instance_id());
+        if is_source || already_pierced {
+            return;
+        }
+
+        let mut metal_object = self.get_metal_obejct();
+        let velocity = metal_object.bind().get_impact_velocity();
+        let mass = metal_object.get_mass();
+
+        if player.bind().is_parrying() {
+            self.reflect(player, velocity);
+            return;
+        }
+
+        // Hurt the player
+        player.bind_mut().take_damage(DamageEvent::new(
+            source,
+            DamageType::Coin,
+            Self::calculate_damage(velocity, mass),
+            velocity * mass,
+        ));
+
+        self.hits_remaining -= 1;
+        if self.hits_remaining > 0 {
+            metal_object.add_collision_exception_with(&player);
+            metal_object.set_linear_velocity(velocity * PIERCE_SPEED_RETAINED);
+            self.pierced.push(player);
         } else {
-            // Change the state to idle
-            self.set_state(CoinState::Idle);
+            self.settle();
+        }
+    }
+
+    /// Sends the coin back the way it came after being parried. The parrying player becomes
+    /// responsible for the coin and it can pierce through players again.
+    ///
+    /// # Arguments
+    /// * `player` (`Gd<Player>`) - The player that parried the coin
+    /// * `velocity` (Vector2) - The velocity of the coin when it was parried
+    fn reflect(&mut self, player: Gd<Player>, velocity: Vector2) {
+        let mut metal_object = self.get_metal_obejct();
+        for pierced in self.pierced.drain(..) {
+            if pierced.is_instance_valid() {
+                metal_object.remove_collision_exception_with(&pierced);
+            }
+        }
+
+        self.hits_remaining = COIN_PIERCE_HITS;
+        metal_object.bind_mut().set_last_pusher(player);
+        metal_object.set_linear_velocity(-velocity);
+    }
+
+    /// Calculates the damage a coin deals from its kinetic energy, so a coin pushed with steel
+    /// is lethal while a lobbed coin barely hurts.
+    ///
+    /// # Arguments
+    /// * `velocity` (Vector2) - The velocity of the coin when it hit
+    /// * `mass` (f32) - The mass of the coin
+    ///
+    /// # Returns
+    /// * `f64` - The damage the coin deals
+    fn calculate_damage(velocity: Vector2, mass: f32) -> f64 {
+        let speed = velocity.length() as f64;
+        let kinetic_energy = 0.5 * mass as f64 * speed * speed;
+
+        (kinetic_energy * COIN_ENERGY_TO_DAMAGE).clamp(COIN_MIN_DAMAGE, COIN_MAX_DAMAGE)
+    }
+
+    /// Gets the player responsible for the coin. A player that recently pushed or pulled the
+    /// coin takes credit over the player that threw it.
+    ///
+    /// # Returns
+    /// * `Option<Gd<Player>>` - The player responsible for the coin, if there is one
+    fn get_source(&self) -> Option<Gd<Player>> {
+        self.get_metal_obejct()
+            .bind()
+            .get_last_pusher()
+            .or_else(|| self.curr_player.clone())
+    }
+
+    /// Checks if an idle coin was pushed or pulled into a player fast enough to hurt them.
+    ///
+    /// # Arguments
+    /// * `player` (`&Gd<Player>`) - The player the coin entered
+    ///
+    /// # Returns
+    /// * `bool` - True if someone else sent the coin flying into the player
+    fn is_pushed_into(&self, player: &Gd<Player>) -> bool {
+        let metal_object = self.get_metal_obejct();
+        let pusher = metal_object.bind().get_last_pusher();
+
+        pusher.is_some_and(|pusher| pusher.instance_id() != player.instance_id())
+            && metal_object.get_linear_velocity().length() > RICOCHET_SPEED
+    }
+
+    /// Stops the coin from doing damage so it can be picked up again, letting it collide with
+    /// any players it pierced through.
+    fn settle(&mut self) {
+        self.set_state(CoinState::Idle);
+
+        let mut metal_object = self.get_metal_obejct();
+        for player in self.pierced.drain(..) {
+            if player.is_instance_valid() {
+                metal_object.remove_collision_exception_with(&player);
+            }
         }
     }
 
@@ -4,41 +4,81 @@ use godot::{classes::Engine, prelude::*};
 use settings::Settings;
 pub mod player {
 
+    pub mod analog_profile;
+    pub mod attack_data;
+    pub mod bot_controller;
+    pub mod bronze_radar;
+    pub mod copper_cloud;
+    pub mod damage_event;
+    pub mod damage_resolver;
     pub mod disconnected;
+    pub mod gesture_recognizer;
+    pub mod input_buffer;
+    pub mod input_frame;
     pub mod input_manager;
+    pub mod keyboard_profile;
     pub mod metal_line;
     pub mod metal_manager;
     pub mod player;
+    pub mod player_bindings;
     pub mod player_tin_light;
+    pub mod state_record;
+    pub mod state_transitions;
 
     pub mod enums {
+        pub mod aim_source;
+        pub mod analog_input;
+        pub mod attack_kind;
+        pub mod bot_difficulty;
         pub mod coin_events;
+        pub mod damage_type;
         pub mod force;
+        pub mod gesture;
+        pub mod guard_outcome;
+        pub mod input_action;
+        pub mod input_binding;
         pub mod metal_type;
         pub mod player_events;
         pub mod player_states;
+        pub mod response_curve;
         pub mod timeout_events;
+        pub mod transition_condition;
     }
 
     pub mod traits {
+        pub mod input_source;
         pub mod metal;
         pub mod player_state;
     }
 
+    pub mod input_sources {
+        pub mod hardware_input;
+        pub mod network_input;
+        pub mod replay_input;
+        pub mod scripted_input;
+    }
+
     pub mod player_states {
         pub mod attack;
+        pub mod block;
         pub mod crouch;
         pub mod crouch_end;
         pub mod crouch_start;
         pub mod fall;
+        pub mod hurt;
         pub mod idle;
         pub mod jump;
         pub mod land;
+        pub mod ledge_climb;
+        pub mod ledge_grab;
         pub mod roll;
         pub mod run;
         pub mod slide;
         pub mod slide_crouch;
         pub mod sprint;
+        pub mod stumble;
+        pub mod wall_jump;
+        pub mod wall_slide;
     }
 
     pub mod metals {
@@ -58,6 +98,7 @@ pub mod map_light;
 pub mod metal_object;
 pub mod metal_pickups;
 pub mod player_light;
+pub mod player_stats;
 pub mod settings;
 pub mod split_screen;
 
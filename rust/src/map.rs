@@ -1,14 +1,15 @@
 //! map.rs
 //!
 //! This file contains the Map class, which is responsible for managing the spawn points in the game.
-//! It includes functions for initializing the spawn points and retrieving their positions.
+//! It includes functions for initializing the spawn points and retrieving their positions, and for
+//! checking where the map's solid tiles are so bots can find their way around.
 //!
 //! Author: Charles Barth
 //! Version: Spring 2025
 use std::collections::HashMap;
 
 use godot::{
-    classes::{INode2D, Marker2D, Node2D},
+    classes::{INode2D, Marker2D, Node2D, TileMapLayer},
     prelude::*,
 };
 
@@ -20,6 +21,8 @@ pub struct Map {
     base: Base<Node2D>,
     /// A HashMap of spawn point names to positions
     spawn_points: HashMap<String, Vector2>,
+    /// The tilemap layers that players collide with
+    solid_layers: Vec<Gd<TileMapLayer>>,
 }
 
 #[godot_api]
@@ -33,6 +36,7 @@ impl INode2D for Map {
         Self {
             base,
             spawn_points: HashMap::new(),
+            solid_layers: Vec::new(),
         }
     }
 
@@ -45,6 +49,24 @@ impl INode2D for Map {
             let position = marker.get_position();
             self.spawn_points.insert(name, position);
         }
+
+        // Layers can be nested in other nodes or in an instanced level, so search the whole map
+        let layers = self
+            .base()
+            .find_children_ex("*")
+            .type_("TileMapLayer")
+            .owned(false)
+            .done();
+        self.solid_layers = layers
+            .iter_shared()
+            .map(|layer| layer.cast::<TileMapLayer>())
+            .filter(|layer| {
+                layer.is_collision_enabled()
+                    && layer
+                        .get_tile_set()
+                        .is_some_and(|tile_set| tile_set.get_physics_layers_count() > 0)
+            })
+            .collect();
     }
 }
 
@@ -63,4 +85,21 @@ impl Map {
             .expect("Spawn point not found")
             .clone()
     }
+
+    /// This function checks if there is a solid tile at a position.
+    ///
+    /// # Arguments
+    /// * `position` - The global position to check.
+    ///
+    /// # Returns
+    /// * `bool` - True if a tile players collide with covers the position.
+    #[func]
+    pub fn is_solid(&self, position: Vector2) -> bool {
+        self.solid_layers.iter().any(|layer| {
+            let cell = layer.local_to_map(layer.to_local(position));
+            layer
+                .get_cell_tile_data(cell)
+                .is_some_and(|tile| tile.get_collision_polygons_count(0) > 0)
+        })
+    }
 }
@@ -7,6 +7,7 @@
 //! Author: Charles Barth
 //! Version: Spring 2025
 use std::collections::VecDeque;
+use std::time::{Duration, Instant};
 
 use godot::{
     classes::{
@@ -16,7 +17,10 @@ use godot::{
 };
 
 use crate::{
-    player::{enums::force::Force, player::Player},
+    player::{
+        enums::{damage_type::DamageType, force::Force},
+        player::Player,
+    },
     settings::Settings,
 };
 
@@ -34,8 +38,19 @@ pub struct MetalObject {
     gravity: f64,
     /// The mass of the MetalObject
     mass: f32,
+    /// The player that last pushed or pulled the MetalObject and when they did it
+    last_pusher: Option<(Gd<Player>, Instant)>,
+    /// The velocity of the MetalObject before any collisions were handled this physics frame
+    impact_velocity: Vector2,
 }
 
+/// How long a player is held responsible for a MetalObject after pushing or pulling it
+const PUSHER_MEMORY: Duration = Duration::from_secs(3);
+/// The speed into a tile a MetalObject needs to bounce off of it instead of stopping
+pub const RICOCHET_SPEED: f32 = 600.0;
+/// The fraction of the speed into a tile that a MetalObject keeps when it ricochets
+const RICOCHET_RESTITUTION: f32 = 0.6;
+
 /// IRigidBody2D methods for MetalObject
 #[godot_api]
 impl IRigidBody2D for MetalObject {
@@ -63,6 +78,8 @@ impl IRigidBody2D for MetalObject {
             delta: 0.0,
             gravity,
             mass: 0.1,
+            last_pusher: None,
+            impact_velocity: Vector2::ZERO,
         }
     }
 
@@ -89,6 +106,7 @@ impl IRigidBody2D for MetalObject {
             base_velocity.y += (self.gravity * self.delta) as f32;
             let result = self.apply_forces(base_velocity);
             base_velocity = result.0;
+            self.impact_velocity = base_velocity;
 
             let body_position = self.base().get_position();
             for i in 0..body.get_contact_count() {
@@ -193,17 +211,24 @@ impl MetalObject {
 
         if let Some(collision_body) = collision_body_option {
             if collision_body.is_class("TileMapLayer") {
+                // Fast objects bounce off of tiles, losing some energy, slow ones stop
                 if (direction == "Down" && base_velocity.y.signum() == 1.0)
                     || (direction == "Up" && base_velocity.y.signum() == -1.0)
                 {
-                    base_velocity.y = 0.0;
+                    base_velocity.y = Self::ricochet(base_velocity.y);
                 } else if (direction == "Right" && base_velocity.x.signum() == 1.0)
                     || (direction == "Left" && base_velocity.x.signum() == -1.0)
                 {
-                    base_velocity.x = 0.0;
+                    base_velocity.x = Self::ricochet(base_velocity.x);
                 }
             } else if collision_body.is_class("Player") {
                 let mut player = collision_body.try_cast::<Player>().unwrap();
+
+                // The player that sent this object into the player is held responsible
+                if let Some(pusher) = self.get_last_pusher() {
+                    player.bind_mut().tag_by(pusher, DamageType::Impact);
+                }
+
                 base_velocity = player.bind_mut().impact(self.mass, base_velocity);
             } else if collision_body.is_class("MetalObject") {
                 let mut metal_object = collision_body.try_cast::<MetalObject>().unwrap();
@@ -214,6 +239,55 @@ impl MetalObject {
         base_velocity
     }
 
+    /// This function calculates the speed of the object along the normal of a tile it hit.
+    ///
+    /// # Arguments
+    /// * `speed` - The speed of the object into the tile.
+    ///
+    /// # Returns
+    /// * `f32` - The reflected speed if the object ricochets, otherwise 0.
+    fn ricochet(speed: f32) -> f32 {
+        if speed.abs() > RICOCHET_SPEED {
+            -speed * RICOCHET_RESTITUTION
+        } else {
+            0.0
+        }
+    }
+
+    /// This function gets the velocity of the object before it collided with anything in the
+    /// last physics frame. This is the velocity things it hit should be damaged by.
+    ///
+    /// # Returns
+    /// * `Vector2` - The velocity of the object before any collisions.
+    pub fn get_impact_velocity(&self) -> Vector2 {
+        self.impact_velocity
+    }
+
+    /// This function gets the player that recently pushed or pulled the object.
+    ///
+    /// # Returns
+    /// * `Option<Gd<Player>>` - The player if they pushed or pulled the object within the
+    ///                          pusher memory and are still in the game.
+    pub fn get_last_pusher(&self) -> Option<Gd<Player>> {
+        match self.last_pusher.as_ref() {
+            Some((pusher, time))
+                if time.elapsed() <= PUSHER_MEMORY && pusher.is_instance_valid() =>
+            {
+                Some(pusher.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// This function makes a player responsible for the object, such as when they push it or
+    /// parry it back.
+    ///
+    /// # Arguments
+    /// * `player` - The player responsible for the object.
+    pub fn set_last_pusher(&mut self, player: Gd<Player>) {
+        self.last_pusher = Some((player, Instant::now()));
+    }
+
     /// This function is the way to determine if the object is metal.
     ///
     /// # Returns
@@ -321,6 +395,7 @@ impl MetalObject {
                 acceleration,
                 player,
             } => {
+                self.set_last_pusher(player.clone());
                 expected_forces.push_back(Force::PlayerSteelPush {
                     acceleration: acceleration.clone(),
                     player,
@@ -0,0 +1,238 @@
+//! analog_profile.rs
+//!
+//! This file contains the analog settings of a player. Every stick and trigger value read from a
+//! controller is passed through the player's profile before the game uses it, so a worn stick that
+//! rests slightly off center can be given a larger deadzone and a stick that never quite reaches
+//! its edge can be given a smaller outer deadzone.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use godot::prelude::*;
+
+use super::enums::analog_input::AnalogInput;
+use super::enums::response_curve::ResponseCurve;
+
+/// The deadzones and response curve of a stick or trigger.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AnalogResponse {
+    /// How far the input has to be pushed before it counts, from 0 to 1.
+    pub inner_deadzone: f32,
+    /// How far the input has to be pushed to count as fully pushed, from 0 to 1.
+    pub outer_deadzone: f32,
+    /// How the input responds between the deadzones.
+    pub curve: ResponseCurve,
+}
+
+impl AnalogResponse {
+    /// Applies the deadzones and curve to a trigger or a single axis of a stick.
+    /// The input is rescaled so it starts from 0 at the inner deadzone and reaches 1 at the outer
+    /// deadzone.
+    ///
+    /// # Arguments
+    /// * `value` - The raw value of the input from -1 to 1.
+    ///
+    /// # Returns
+    /// * `f32` - The value to use, from -1 to 1.
+    pub fn apply(&self, value: f32) -> f32 {
+        value.signum() * self.shape(value.abs())
+    }
+
+    /// Applies the deadzones and curve to both axes of a stick at once. The deadzones are round,
+    /// so pushing the stick along a diagonal is no different from pushing it straight.
+    ///
+    /// # Arguments
+    /// * `value` - The raw position of the stick.
+    ///
+    /// # Returns
+    /// * `Vector2` - The position to use, no longer than 1.
+    pub fn apply_stick(&self, value: Vector2) -> Vector2 {
+        let length = value.length();
+        if length == 0.0 {
+            return Vector2::ZERO;
+        }
+
+        value / length * self.shape(length)
+    }
+
+    /// Applies the deadzones and curve to how far an input is pushed.
+    ///
+    /// # Arguments
+    /// * `magnitude` - How far the input is pushed from 0 to 1.
+    ///
+    /// # Returns
+    /// * `f32` - The shaped input from 0 to 1.
+    fn shape(&self, magnitude: f32) -> f32 {
+        if magnitude <= self.inner_deadzone {
+            return 0.0;
+        }
+
+        let range = (self.outer_deadzone - self.inner_deadzone).max(f32::EPSILON);
+        let scaled = ((magnitude - self.inner_deadzone) / range).clamp(0.0, 1.0);
+        self.curve.apply(scaled)
+    }
+}
+
+/// Default methods for the AnalogResponse
+impl Default for AnalogResponse {
+    /// The deadzone the sticks used before they could be adjusted
+    fn default() -> Self {
+        Self {
+            inner_deadzone: 0.2,
+            outer_deadzone: 0.95,
+            curve: ResponseCurve::Linear,
+        }
+    }
+}
+
+/// The analog settings of one player.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct AnalogProfile {
+    /// The response of the left stick.
+    pub movement: AnalogResponse,
+    /// The response of the right stick.
+    pub aim: AnalogResponse,
+    /// The response of the triggers.
+    pub triggers: AnalogResponse,
+    /// Whether aiming left and right is flipped.
+    pub invert_aim_x: bool,
+    /// Whether aiming up and down is flipped.
+    pub invert_aim_y: bool,
+}
+
+impl AnalogProfile {
+    /// Gets the response of a stick or the triggers.
+    ///
+    /// # Arguments
+    /// * `input` - The stick or triggers.
+    ///
+    /// # Returns
+    /// * `&mut AnalogResponse` - The response so it can be changed.
+    pub fn response_mut(&mut self, input: AnalogInput) -> &mut AnalogResponse {
+        match input {
+            AnalogInput::Movement => &mut self.movement,
+            AnalogInput::Aim => &mut self.aim,
+            AnalogInput::Triggers => &mut self.triggers,
+        }
+    }
+
+    /// Gets the position of the aim stick to use.
+    ///
+    /// # Arguments
+    /// * `value` - The raw position of the right stick.
+    ///
+    /// # Returns
+    /// * `Vector2` - The aim after the deadzones, curve and inversion are applied.
+    pub fn apply_aim(&self, value: Vector2) -> Vector2 {
+        let mut aim = self.aim.apply_stick(value);
+        if self.invert_aim_x {
+            aim.x = -aim.x;
+        }
+        if self.invert_aim_y {
+            aim.y = -aim.y;
+        }
+        aim
+    }
+}
+
+/// Default methods for the AnalogProfile
+impl Default for AnalogProfile {
+    /// Triggers have a smaller deadzone than the sticks as they rest at 0 rather than drifting
+    /// around the center.
+    fn default() -> Self {
+        Self {
+            movement: AnalogResponse::default(),
+            aim: AnalogResponse::default(),
+            triggers: AnalogResponse {
+                inner_deadzone: 0.1,
+                outer_deadzone: 0.95,
+                curve: ResponseCurve::Linear,
+            },
+            invert_aim_x: false,
+            invert_aim_y: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks that two values are equal apart from rounding
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-5,
+            "{} is not {}",
+            actual,
+            expected
+        );
+    }
+
+    /// A response with round numbers for its deadzones
+    fn response(curve: ResponseCurve) -> AnalogResponse {
+        AnalogResponse {
+            inner_deadzone: 0.2,
+            outer_deadzone: 0.8,
+            curve,
+        }
+    }
+
+    #[test]
+    fn input_inside_inner_deadzone_is_ignored() {
+        let response = response(ResponseCurve::Linear);
+
+        assert_eq!(response.apply(0.0), 0.0);
+        assert_eq!(response.apply(0.2), 0.0);
+        assert_eq!(response.apply(-0.15), 0.0);
+    }
+
+    #[test]
+    fn input_is_rescaled_between_deadzones() {
+        let response = response(ResponseCurve::Linear);
+
+        assert_close(response.apply(0.5), 0.5);
+        assert_close(response.apply(-0.5), -0.5);
+        assert_close(response.apply(0.8), 1.0);
+        assert_close(response.apply(1.0), 1.0);
+    }
+
+    #[test]
+    fn curve_is_applied_after_deadzones() {
+        let response = response(ResponseCurve::Quadratic);
+
+        assert_close(response.apply(0.5), 0.25);
+        assert_close(response.apply(-0.5), -0.25);
+    }
+
+    #[test]
+    fn stick_deadzone_is_round() {
+        let response = response(ResponseCurve::Linear);
+
+        assert_eq!(response.apply_stick(Vector2::ZERO), Vector2::ZERO);
+        assert_eq!(response.apply_stick(Vector2::new(0.1, 0.1)), Vector2::ZERO);
+
+        let diagonal = response.apply_stick(Vector2::new(0.5, 0.5).normalized() * 0.5);
+        assert_close(diagonal.length(), 0.5);
+        assert_close(diagonal.x, diagonal.y);
+    }
+
+    #[test]
+    fn stick_is_never_longer_than_one() {
+        let response = response(ResponseCurve::Linear);
+
+        let stick = response.apply_stick(Vector2::new(1.0, 1.0));
+        assert_close(stick.length(), 1.0);
+    }
+
+    #[test]
+    fn aim_can_be_inverted() {
+        let profile = AnalogProfile {
+            aim: response(ResponseCurve::Linear),
+            invert_aim_y: true,
+            ..AnalogProfile::default()
+        };
+
+        let aim = profile.apply_aim(Vector2::new(0.0, 0.5));
+        assert_close(aim.x, 0.0);
+        assert_close(aim.y, -0.5);
+    }
+}
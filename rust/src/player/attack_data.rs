@@ -0,0 +1,133 @@
+//! attack_data.rs
+//!
+//! This file contains the data that defines every melee attack. Each attack has its own hitbox,
+//! damage, knockback and timing windows so that attacks can be tuned here without touching the
+//! Attack state.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use std::time::Duration;
+
+use godot::builtin::Vector2;
+
+use super::enums::attack_kind::AttackKind;
+
+/// The data that defines a melee attack.
+/// Offsets, knockback and lunges are given for a player facing right and are mirrored when the
+/// player faces left.
+pub struct AttackData {
+    /// The animation played during the attack
+    pub animation: &'static str,
+    /// The position of the center of the hitbox relative to the player
+    pub hitbox_offset: Vector2,
+    /// The size of the hitbox
+    pub hitbox_size: Vector2,
+    /// The damage dealt to players hit by the attack
+    pub damage: f64,
+    /// The knockback given to players hit by the attack, pointing away from the attacker
+    pub knockback: Vector2,
+    /// The impulse given to the attacker when the attack starts
+    pub lunge: Vector2,
+    /// How long into the attack the hitbox turns on
+    pub active_start: Duration,
+    /// How long into the attack the hitbox turns off
+    pub active_end: Duration,
+    /// How long into the attack the player can cancel it into the next attack, a jump or a roll
+    pub cancel_start: Duration,
+    /// How long the attack lasts
+    pub duration: Duration,
+    /// The attack that follows this one in a combo
+    pub next: Option<AttackKind>,
+}
+
+impl AttackData {
+    /// Gets the data for an attack.
+    ///
+    /// # Arguments
+    /// * `kind` - The attack to get the data of.
+    ///
+    /// # Returns
+    /// * `AttackData` - The data that defines the attack.
+    pub fn for_kind(kind: AttackKind) -> AttackData {
+        match kind {
+            AttackKind::Jab => AttackData {
+                animation: "attack",
+                hitbox_offset: Vector2::new(19.75, -1.0),
+                hitbox_size: Vector2::new(24.5, 39.0),
+                damage: 15.0,
+                knockback: Vector2::new(120.0, -60.0),
+                lunge: Vector2::new(40.0, 0.0),
+                active_start: Duration::from_millis(60),
+                active_end: Duration::from_millis(180),
+                cancel_start: Duration::from_millis(150),
+                duration: Duration::from_millis(320),
+                next: Some(AttackKind::Cross),
+            },
+            AttackKind::Cross => AttackData {
+                animation: "attack",
+                hitbox_offset: Vector2::new(22.0, -1.0),
+                hitbox_size: Vector2::new(28.0, 36.0),
+                damage: 18.0,
+                knockback: Vector2::new(150.0, -80.0),
+                lunge: Vector2::new(60.0, 0.0),
+                active_start: Duration::from_millis(60),
+                active_end: Duration::from_millis(200),
+                cancel_start: Duration::from_millis(180),
+                duration: Duration::from_millis(360),
+                next: Some(AttackKind::Finisher),
+            },
+            AttackKind::Finisher => AttackData {
+                animation: "attack",
+                hitbox_offset: Vector2::new(24.0, -4.0),
+                hitbox_size: Vector2::new(32.0, 44.0),
+                damage: 30.0,
+                knockback: Vector2::new(320.0, -220.0),
+                lunge: Vector2::new(90.0, 0.0),
+                active_start: Duration::from_millis(100),
+                active_end: Duration::from_millis(260),
+                cancel_start: Duration::from_millis(320),
+                duration: Duration::from_millis(480),
+                next: None,
+            },
+            AttackKind::Up => AttackData {
+                animation: "attack",
+                hitbox_offset: Vector2::new(2.0, -30.0),
+                hitbox_size: Vector2::new(30.0, 24.0),
+                damage: 20.0,
+                knockback: Vector2::new(40.0, -320.0),
+                lunge: Vector2::ZERO,
+                active_start: Duration::from_millis(80),
+                active_end: Duration::from_millis(220),
+                cancel_start: Duration::from_millis(260),
+                duration: Duration::from_millis(400),
+                next: None,
+            },
+            AttackKind::DownAir => AttackData {
+                animation: "attack",
+                hitbox_offset: Vector2::new(0.0, 26.0),
+                hitbox_size: Vector2::new(26.0, 24.0),
+                damage: 25.0,
+                knockback: Vector2::new(60.0, 300.0),
+                lunge: Vector2::new(0.0, 250.0),
+                active_start: Duration::from_millis(60),
+                active_end: Duration::from_millis(600),
+                cancel_start: Duration::from_millis(600),
+                duration: Duration::from_millis(600),
+                next: None,
+            },
+            AttackKind::Dash => AttackData {
+                animation: "dash",
+                hitbox_offset: Vector2::new(22.0, -1.0),
+                hitbox_size: Vector2::new(34.0, 36.0),
+                damage: 28.0,
+                knockback: Vector2::new(300.0, -140.0),
+                lunge: Vector2::new(350.0, 0.0),
+                active_start: Duration::from_millis(40),
+                active_end: Duration::from_millis(260),
+                cancel_start: Duration::from_millis(300),
+                duration: Duration::from_millis(440),
+                next: None,
+            },
+        }
+    }
+}
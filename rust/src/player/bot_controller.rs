@@ -0,0 +1,448 @@
+//! bot_controller.rs
+//!
+//! This module defines the BotController class, which plays a player for the computer. A bot looks
+//! at the map, its opponents and the metal around it, decides which buttons to press and feeds them
+//! to its player's input manager as input frames. Bots never move their player directly, so they
+//! are held to the same states, metals and reserves as everyone else.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+use godot::classes::{INode, Node, RandomNumberGenerator};
+use godot::prelude::*;
+
+use super::enums::bot_difficulty::BotDifficulty;
+use super::enums::input_action::InputAction;
+use super::enums::metal_type::{BurnType, MetalType};
+use super::enums::player_events::PlayerEvents;
+use super::input_frame::InputFrame;
+use super::player::Player;
+use crate::game::Game;
+
+/// The device id of the first bot. Each bot after it counts down from here so bots never share a
+/// device id with a controller, the keyboard or each other.
+pub const FIRST_BOT_DEVICE_ID: i32 = -10;
+/// How close an opponent has to be side to side before the bot attacks
+const MELEE_RANGE: f32 = 32.0;
+/// How far apart the bot and an opponent can be up and down while still being on the same level
+const LEVEL_HEIGHT: f32 = 32.0;
+/// How far away an opponent can be for the bot to throw coins at them
+const THROW_RANGE: f32 = 320.0;
+/// How long the bot charges a throw at an opponent at the edge of its throwing range
+const MAX_THROW_CHARGE: Duration = Duration::from_millis(1500);
+/// How long the bot waits after throwing a coin before throwing another
+const THROW_COOLDOWN: Duration = Duration::from_millis(2000);
+/// How far away an opponent has to be before the bot pulls itself towards them with iron
+const PULL_RANGE: f32 = 240.0;
+/// How far a metal object can be from an opponent's direction, in radians, for the bot to push or
+/// pull it towards them
+const METAL_ANGLE: f32 = 0.35;
+/// How close a metal object has to be below the bot to launch it into the air
+const LAUNCH_RANGE: f32 = 96.0;
+/// How many physics frames the bot aims at a metal object before burning, so the object is
+/// selected before the burn starts
+const AIM_SETTLE_FRAMES: u32 = 2;
+/// How far ahead of the bot the map is checked for walls and gaps
+const PROBE_DISTANCE: f32 = 16.0;
+/// The distance from the center of a player down to their feet
+const FEET_OFFSET: f32 = 23.0;
+/// How far above the bot an opponent has to be before the bot jumps up towards them
+const JUMP_HEIGHT: f32 = 48.0;
+
+/// What a bot has decided to do until it makes its next decision.
+#[derive(Default)]
+struct BotIntent {
+    /// How far the bot is moving left or right from -1 to 1.
+    horizontal: f32,
+    /// The direction the bot is aiming in.
+    aim: Vector2,
+    /// The buttons the bot is holding down.
+    buttons: HashSet<InputAction>,
+    /// The metal the bot burns once it has aimed at its target for long enough.
+    metal: Option<MetalType>,
+}
+
+/// The BotController class plays its parent player for the computer.
+#[derive(GodotClass)]
+#[class(base=Node)]
+pub struct BotController {
+    /// The base node of the BotController.
+    base: Base<Node>,
+    /// How quickly the bot reacts and how well it aims.
+    difficulty: BotDifficulty,
+    /// Used to vary the bot's reaction time and aim.
+    rng: Gd<RandomNumberGenerator>,
+    /// What the bot is doing until its next decision.
+    intent: BotIntent,
+    /// The buttons the bot held down in the last frame it sent.
+    held: HashSet<InputAction>,
+    /// Buttons that are pressed again at the bot's latest decision while they are still held, so
+    /// they have to be released first.
+    retap: Vec<InputAction>,
+    /// When the bot makes its next decision.
+    next_decision: Instant,
+    /// The number of frames sent since the bot's latest decision.
+    frames_since_decision: u32,
+    /// When the bot lets go of the coin it is charging up, if it is throwing one.
+    throw_release: Option<Instant>,
+    /// When the bot last threw a coin.
+    last_throw: Option<Instant>,
+    /// Whether the bot has turned on low burning steel and iron so it can pick targets.
+    low_burn_started: bool,
+}
+
+/// INode methods for the BotController
+#[godot_api]
+impl INode for BotController {
+    /// The Godot constructor for the BotController class.
+    ///
+    /// # Arguments
+    /// * `base` - The base node of the BotController.
+    ///
+    /// # Returns
+    /// * `BotController` - A new instance of the BotController class.
+    fn init(base: Base<Node>) -> Self {
+        Self {
+            base,
+            difficulty: BotDifficulty::Normal,
+            rng: RandomNumberGenerator::new_gd(),
+            intent: BotIntent::default(),
+            held: HashSet::new(),
+            retap: Vec::new(),
+            next_decision: Instant::now(),
+            frames_since_decision: 0,
+            throw_release: None,
+            last_throw: None,
+            low_burn_started: false,
+        }
+    }
+
+    /// This is a built in method for Godot that is called when the node is added to the scene
+    /// tree. The player is switched over to scripted input so it only listens to the bot.
+    fn ready(&mut self) {
+        self.rng.randomize();
+
+        let mut input_manager = self.get_player().bind_mut().get_input_manager();
+        let mut input_manager = input_manager.bind_mut();
+        input_manager.use_scripted_input();
+        // The bot presses metal buttons directly rather than tapping and holding them
+        input_manager.set_metal_gestures(false);
+    }
+
+    /// This is a built in method for Godot that is called every physics frame.
+    /// The bot makes a new decision whenever its reaction time has passed and sends the player a
+    /// frame of input every physics frame.
+    ///
+    /// # Arguments
+    /// * `delta` - The time since the last frame.
+    fn physics_process(&mut self, _delta: f64) {
+        let now = Instant::now();
+        if now >= self.next_decision {
+            self.decide(now);
+        }
+
+        let frame = self.next_frame(now);
+        self.frames_since_decision += 1;
+
+        let mut input_manager = self.get_player().bind_mut().get_input_manager();
+        input_manager.bind_mut().push_frame(frame);
+    }
+}
+
+impl BotController {
+    /// Sets how hard the bot is to play against.
+    ///
+    /// # Arguments
+    /// * `difficulty` - The difficulty of the bot.
+    pub fn set_difficulty(&mut self, difficulty: BotDifficulty) {
+        self.difficulty = difficulty;
+    }
+
+    /// Gets the player the bot is playing.
+    ///
+    /// # Returns
+    /// * `Gd<Player>` - The bot's parent player.
+    fn get_player(&self) -> Gd<Player> {
+        self.base()
+            .get_parent()
+            .expect("bot has no player")
+            .cast::<Player>()
+    }
+
+    /// Looks at the bot's surroundings and decides what to do until the next decision.
+    /// The bot attacks opponents that are close enough, uses metal objects between it and its
+    /// opponent, throws coins at opponents on the same level and otherwise heads towards the
+    /// nearest opponent.
+    ///
+    /// # Arguments
+    /// * `now` - The time of the decision.
+    fn decide(&mut self, now: Instant) {
+        let reaction_time = self.difficulty.reaction_time();
+        let variation = self.rng.randf_range(0.75, 1.25);
+        self.next_decision = now + reaction_time.mul_f32(variation);
+        self.frames_since_decision = 0;
+
+        let player = self.get_player();
+        let position = player.get_global_position();
+        let game = player.get_node_as::<Game>("/root/Game");
+
+        let Some(target) = self.find_target(&player, &game) else {
+            self.set_intent(BotIntent::default());
+            return;
+        };
+        let offset = target - position;
+        let facing = player.bind().get_dir().signum() == offset.x.signum();
+
+        let (horizontal, jump) = self.steer(&player, &game, offset);
+        let mut intent = BotIntent {
+            horizontal,
+            aim: self.aim_at(offset),
+            ..Default::default()
+        };
+        if jump {
+            intent
+                .buttons
+                .insert(InputAction::Player(PlayerEvents::Jump));
+        }
+
+        if offset.x.abs() < MELEE_RANGE && offset.y.abs() < LEVEL_HEIGHT {
+            // Step towards the opponent just enough to turn around
+            intent.horizontal = if facing { 0.0 } else { offset.x.signum() * 0.2 };
+            intent
+                .buttons
+                .insert(InputAction::Player(PlayerEvents::Attack));
+        } else if let Some((metal, object_offset)) = self.choose_metal(&player, offset) {
+            intent.aim = self.aim_at(object_offset);
+            intent.metal = Some(metal);
+        } else if facing
+            && offset.length() < THROW_RANGE
+            && offset.y.abs() < LEVEL_HEIGHT
+            && self.throw_release.is_none()
+            && self
+                .last_throw
+                .is_none_or(|time| now.duration_since(time) > THROW_COOLDOWN)
+        {
+            let charge = (offset.length() / THROW_RANGE).clamp(0.2, 1.0);
+            self.throw_release = Some(now + MAX_THROW_CHARGE.mul_f32(charge));
+            self.last_throw = Some(now);
+        }
+
+        self.set_intent(intent);
+    }
+
+    /// Replaces what the bot is doing. Jumps and attacks only happen when their button goes
+    /// down, so any that are still held from the last decision are released and pressed again.
+    ///
+    /// # Arguments
+    /// * `intent` - What the bot is doing until its next decision.
+    fn set_intent(&mut self, intent: BotIntent) {
+        let taps = [
+            InputAction::Player(PlayerEvents::Jump),
+            InputAction::Player(PlayerEvents::Attack),
+        ];
+        self.retap = taps
+            .into_iter()
+            .filter(|action| intent.buttons.contains(action) && self.held.contains(action))
+            .collect();
+        self.intent = intent;
+    }
+
+    /// Builds the frame of input to send the player this physics frame.
+    ///
+    /// # Arguments
+    /// * `now` - The time of the frame.
+    ///
+    /// # Returns
+    /// * `InputFrame` - The bot's input for the frame.
+    fn next_frame(&mut self, now: Instant) -> InputFrame {
+        let mut buttons = Vec::new();
+
+        // Low burning steel and iron stays on once toggled, which lets the bot pick targets
+        if !self.low_burn_started {
+            let low_burn = InputAction::Player(PlayerEvents::LowBurn);
+            buttons.push((low_burn, true));
+            for metal in [MetalType::Steel, MetalType::Iron] {
+                buttons.push((InputAction::Metal(metal), true));
+                buttons.push((InputAction::Metal(metal), false));
+            }
+            buttons.push((low_burn, false));
+            self.low_burn_started = true;
+        }
+
+        let mut wanted = self.intent.buttons.clone();
+        match self.throw_release {
+            Some(release) if now < release => {
+                wanted.insert(InputAction::Player(PlayerEvents::Throw));
+            }
+            _ => self.throw_release = None,
+        }
+        if let Some(metal) = self.intent.metal {
+            if self.frames_since_decision >= AIM_SETTLE_FRAMES {
+                wanted.insert(InputAction::Metal(metal));
+            }
+        }
+
+        for action in self.retap.drain(..) {
+            buttons.push((action, false));
+            self.held.remove(&action);
+        }
+        for action in self.held.difference(&wanted) {
+            buttons.push((*action, false));
+        }
+        for action in wanted.difference(&self.held) {
+            buttons.push((*action, true));
+        }
+
+        let strength = |metal: MetalType| {
+            if wanted.contains(&InputAction::Metal(metal)) {
+                1.0
+            } else {
+                0.0
+            }
+        };
+        let frame = InputFrame {
+            horizontal: self.intent.horizontal,
+            vertical: 0.0,
+            aim: self.intent.aim,
+            steel_strength: strength(MetalType::Steel),
+            iron_strength: strength(MetalType::Iron),
+            buttons,
+        };
+
+        self.held = wanted;
+        frame
+    }
+
+    /// Finds the position of the nearest opponent the bot can see. Teammates and opponents hidden
+    /// by a coppercloud are left out.
+    ///
+    /// # Arguments
+    /// * `player` - The bot's player.
+    /// * `game` - The game the bot is playing in.
+    ///
+    /// # Returns
+    /// * `Option<Vector2>` - The global position of the opponent, or None if there is none.
+    fn find_target(&self, player: &Gd<Player>, game: &Gd<Game>) -> Option<Vector2> {
+        let position = player.get_global_position();
+        let game = game.bind();
+        let teammates = game.get_teammate_ids(player.bind().get_player_id());
+
+        game.get_players()
+            .into_iter()
+            .filter(|other| other.instance_id() != player.instance_id())
+            .filter(|other| !teammates.contains(&other.bind().get_player_id()))
+            .map(|other| other.get_global_position())
+            .filter(|target| !game.is_concealed_from(*target, position))
+            .min_by(|a, b| {
+                position
+                    .distance_squared_to(*a)
+                    .total_cmp(&position.distance_squared_to(*b))
+            })
+    }
+
+    /// Decides which way to walk and whether to jump, using the map's tiles to find walls in the
+    /// way and gaps in the floor.
+    ///
+    /// # Arguments
+    /// * `player` - The bot's player.
+    /// * `game` - The game the bot is playing in.
+    /// * `offset` - The offset from the bot to its opponent.
+    ///
+    /// # Returns
+    /// * `(f32, bool)` - The horizontal movement and whether to jump.
+    fn steer(&self, player: &Gd<Player>, game: &Gd<Game>, offset: Vector2) -> (f32, bool) {
+        let direction = if offset.x.abs() > MELEE_RANGE / 2.0 {
+            offset.x.signum()
+        } else {
+            0.0
+        };
+        if !player.is_on_floor() {
+            return (direction, false);
+        }
+
+        let map = game.bind().get_map();
+        let map = map.bind();
+        let ahead = player.get_global_position() + Vector2::new(direction * PROBE_DISTANCE, 0.0);
+        let wall_ahead = direction != 0.0 && map.is_solid(ahead);
+        let gap_ahead = direction != 0.0 && !map.is_solid(ahead + Vector2::new(0.0, FEET_OFFSET));
+
+        // Drop down gaps towards opponents below, jump over the rest
+        let target_above = offset.y < -JUMP_HEIGHT;
+        let target_below = offset.y > JUMP_HEIGHT;
+        let jump = wall_ahead || (gap_ahead && !target_below) || target_above;
+
+        (direction, jump)
+    }
+
+    /// Picks a metal object to push or pull. The bot pushes objects between it and its opponent
+    /// at them, pushes off objects below it to reach opponents above and pulls itself towards far
+    /// away opponents on objects past it.
+    ///
+    /// # Arguments
+    /// * `player` - The bot's player.
+    /// * `offset` - The offset from the bot to its opponent.
+    ///
+    /// # Returns
+    /// * `Option<(MetalType, Vector2)>` - The metal to burn and the offset to the object, or None
+    ///   if no object is worth using.
+    fn choose_metal(&self, player: &Gd<Player>, offset: Vector2) -> Option<(MetalType, Vector2)> {
+        let position = player.get_global_position();
+        let player = player.bind();
+        let settings = player.get_settings();
+        let settings = settings.bind();
+        let range = |metal: MetalType| {
+            settings
+                .get_metal_range(metal, BurnType::Burn)
+                .min(settings.get_metal_range(metal, BurnType::LowBurn))
+        };
+        let (steel_range, iron_range) = (range(MetalType::Steel), range(MetalType::Iron));
+
+        let mut push = None;
+        let mut launch = None;
+        let mut pull = None;
+        for metal_object in player.get_metal_objects() {
+            // Coins being carried are hidden and can not be used
+            if !metal_object.is_visible() {
+                continue;
+            }
+
+            let object_offset = metal_object.get_global_position() - position;
+            let towards_target = object_offset.angle_to(offset).abs() < METAL_ANGLE;
+            if player.can_sense_metal_object(metal_object, steel_range) {
+                if towards_target && object_offset.length() < offset.length() {
+                    push = push.or(Some(object_offset));
+                } else if offset.y < -JUMP_HEIGHT
+                    && object_offset.length() < LAUNCH_RANGE
+                    && object_offset.angle_to(Vector2::DOWN).abs() < METAL_ANGLE
+                {
+                    launch = launch.or(Some(object_offset));
+                }
+            }
+            if offset.length() > PULL_RANGE
+                && towards_target
+                && object_offset.length() > MELEE_RANGE * 2.0
+                && player.can_sense_metal_object(metal_object, iron_range)
+            {
+                pull = pull.or(Some(object_offset));
+            }
+        }
+
+        push.or(launch)
+            .map(|object_offset| (MetalType::Steel, object_offset))
+            .or(pull.map(|object_offset| (MetalType::Iron, object_offset)))
+    }
+
+    /// Aims in a direction, missing by up to the bot's aim error.
+    ///
+    /// # Arguments
+    /// * `offset` - The offset to aim at.
+    ///
+    /// # Returns
+    /// * `Vector2` - The direction the bot aims in.
+    fn aim_at(&mut self, offset: Vector2) -> Vector2 {
+        let error = self.difficulty.aim_error();
+        Vector2::from_angle(offset.angle() + self.rng.randf_range(-error, error))
+    }
+}
@@ -0,0 +1,210 @@
+//! bronze_radar.rs
+//!
+//! This module defines the BronzeRadar class, which draws indicators on the edge of a player's
+//! screen pointing towards allomantic pulses that the player can sense with bronze but can not
+//! see. Each indicator shows the metals being burned and a rough distance to the pulse.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use godot::{
+    classes::{Camera2D, INode2D, Node2D, ThemeDB},
+    prelude::*,
+};
+
+use super::enums::metal_type::MetalType;
+
+/// The number of pixels that make up a meter, used to display a rough distance
+const PIXELS_PER_METER: f32 = 32.0;
+/// The distance the indicators are kept from the edge of the screen
+const EDGE_MARGIN: f32 = 14.0;
+/// The length of the indicator arrows
+const ARROW_LENGTH: f32 = 8.0;
+/// The width of the base of the indicator arrows
+const ARROW_WIDTH: f32 = 6.0;
+/// The font size used for the indicator labels
+const LABEL_FONT_SIZE: i32 = 6;
+
+/// A pulse sensed by a player burning bronze.
+pub struct RadarPulse {
+    /// The position of the pulse relative to the player sensing it
+    pub offset: Vector2,
+    /// The metals being burned by the source of the pulse
+    pub metals: Vec<MetalType>,
+}
+
+/// The BronzeRadar class is responsible for drawing the direction, metal type and distance of
+/// pulses that are outside of the player's view.
+#[derive(GodotClass)]
+#[class(base=Node2D)]
+pub struct BronzeRadar {
+    /// The base node of the BronzeRadar.
+    base: Base<Node2D>,
+    /// The pulses currently sensed by the player.
+    pulses: Vec<RadarPulse>,
+    /// The player's camera, used to find the edges of the screen.
+    camera: Option<Gd<Camera2D>>,
+}
+
+/// INode2D methods for the BronzeRadar
+#[godot_api]
+impl INode2D for BronzeRadar {
+    /// The Godot constructor for the BronzeRadar class.
+    ///
+    /// # Arguments
+    /// * `base` - The base node of the BronzeRadar.
+    ///
+    /// # Returns
+    /// * `BronzeRadar` - A new instance of the BronzeRadar class.
+    fn init(base: Base<Node2D>) -> Self {
+        Self {
+            base,
+            pulses: Vec::new(),
+            camera: None,
+        }
+    }
+
+    /// This is a built in method for Godot that is called when the node needs to be redrawn.
+    /// Pulses that are already on screen are skipped since their particles can be seen.
+    fn draw(&mut self) {
+        if self.pulses.is_empty() {
+            return;
+        }
+
+        let camera = self.get_camera();
+        let zoom = camera.get_zoom();
+        let screen_size = self.base().get_viewport_rect().size;
+        let half_extents = Vector2::new(screen_size.x / zoom.x / 2.0, screen_size.y / zoom.y / 2.0);
+        let center = camera.get_position();
+        let font = ThemeDB::singleton().get_fallback_font();
+
+        let mut indicators = Vec::new();
+        for pulse in self.pulses.iter() {
+            let offset = pulse.offset - center;
+            if offset.x.abs() <= half_extents.x && offset.y.abs() <= half_extents.y {
+                continue;
+            }
+
+            let direction = offset.normalized();
+            let edge_position = center + Self::clamp_to_edge(offset, half_extents);
+            indicators.push((
+                edge_position,
+                direction,
+                pulse.metals.clone(),
+                pulse.offset.length() / PIXELS_PER_METER,
+            ));
+        }
+
+        let mut base = self.base_mut();
+        for (position, direction, metals, distance) in indicators {
+            let color = metals
+                .first()
+                .map(|metal| Self::pulse_color(*metal))
+                .unwrap_or(Color::WHITE);
+
+            let tip = position + direction * ARROW_LENGTH;
+            let side = direction.orthogonal() * (ARROW_WIDTH / 2.0);
+            let mut arrow = PackedVector2Array::new();
+            arrow.push(tip);
+            arrow.push(position + side);
+            arrow.push(position - side);
+            base.draw_colored_polygon(&arrow, color);
+
+            let Some(font) = font.as_ref() else {
+                continue;
+            };
+
+            // Labels are placed on the inside of the arrow so they stay on screen
+            let mut label_position = position - direction * (ARROW_LENGTH + LABEL_FONT_SIZE as f32);
+            base.draw_string_ex(font, label_position, format!("{:.0}m", distance).as_str())
+                .font_size(LABEL_FONT_SIZE)
+                .modulate(Color::WHITE)
+                .done();
+
+            for metal in metals {
+                label_position.y += LABEL_FONT_SIZE as f32;
+                base.draw_string_ex(font, label_position, metal.as_str())
+                    .font_size(LABEL_FONT_SIZE)
+                    .modulate(Self::pulse_color(metal))
+                    .done();
+            }
+        }
+    }
+}
+
+impl BronzeRadar {
+    /// Replaces the pulses shown on the radar and redraws it.
+    ///
+    /// # Arguments
+    /// * `pulses` - The pulses currently sensed by the player.
+    pub fn set_pulses(&mut self, pulses: Vec<RadarPulse>) {
+        self.pulses = pulses;
+        self.base_mut().queue_redraw();
+    }
+
+    /// Removes every pulse from the radar.
+    pub fn clear(&mut self) {
+        if self.pulses.is_empty() {
+            return;
+        }
+
+        self.pulses.clear();
+        self.base_mut().queue_redraw();
+    }
+
+    /// Getter for the player's camera.
+    /// This caches the camera so that it does not have to be found every redraw.
+    ///
+    /// # Returns
+    /// * `Gd<Camera2D>` - The camera of the player this radar belongs to.
+    fn get_camera(&mut self) -> Gd<Camera2D> {
+        if self.camera.is_none() {
+            self.camera = Some(self.base().get_node_as::<Camera2D>("../Camera2D"));
+        }
+
+        self.camera.clone().unwrap()
+    }
+
+    /// Scales an offset so that it lands on the edge of the screen, minus a margin.
+    ///
+    /// # Arguments
+    /// * `offset` - The offset from the center of the screen to the pulse.
+    /// * `half_extents` - Half of the size of the screen in world units.
+    ///
+    /// # Returns
+    /// * `Vector2` - The offset from the center of the screen to the indicator.
+    fn clamp_to_edge(offset: Vector2, half_extents: Vector2) -> Vector2 {
+        let inner_x = (half_extents.x - EDGE_MARGIN).max(0.0);
+        let inner_y = (half_extents.y - EDGE_MARGIN).max(0.0);
+
+        let scale_x = if offset.x != 0.0 {
+            inner_x / offset.x.abs()
+        } else {
+            f32::MAX
+        };
+        let scale_y = if offset.y != 0.0 {
+            inner_y / offset.y.abs()
+        } else {
+            f32::MAX
+        };
+
+        offset * scale_x.min(scale_y)
+    }
+
+    /// Gets the color used to show a metal on the radar.
+    ///
+    /// # Arguments
+    /// * `metal` - The metal to get the color of.
+    ///
+    /// # Returns
+    /// * `Color` - The color of the metal's pulse.
+    fn pulse_color(metal: MetalType) -> Color {
+        match metal {
+            MetalType::Pewter => Color::from_rgb(0.85, 0.85, 0.8),
+            MetalType::Steel => Color::from_rgb(0.2, 0.6, 1.0),
+            MetalType::Iron => Color::from_rgb(0.6, 0.65, 0.7),
+            MetalType::Tin => Color::from_rgb(0.75, 0.9, 0.95),
+            MetalType::Copper => Color::from_rgb(0.72, 0.45, 0.2),
+            MetalType::Bronze => Color::from_rgb(0.8, 0.5, 0.2),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! copper_cloud.rs
+//!
+//! This module defines the CopperCloud class, which draws the area covered by a player's
+//! coppercloud. The cloud is only drawn on the visibility layers of the burner and their team
+//! so opponents can not see where the cloud ends.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use godot::{
+    classes::{INode2D, Node2D},
+    prelude::*,
+};
+
+/// The color used to fill the coppercloud
+const CLOUD_FILL_COLOR: Color = Color::from_rgba(0.72, 0.45, 0.2, 0.08);
+/// The color used to outline the coppercloud
+const CLOUD_EDGE_COLOR: Color = Color::from_rgba(0.72, 0.45, 0.2, 0.5);
+/// The number of points used to draw the outline of the coppercloud
+const CLOUD_EDGE_POINTS: i32 = 64;
+
+/// The CopperCloud class is responsible for drawing the radius of a player's coppercloud.
+#[derive(GodotClass)]
+#[class(base=Node2D)]
+pub struct CopperCloud {
+    /// The base node of the CopperCloud.
+    base: Base<Node2D>,
+    /// The radius of the cloud, a radius of 0 means the cloud is not active.
+    radius: f32,
+}
+
+/// INode2D methods for the CopperCloud
+#[godot_api]
+impl INode2D for CopperCloud {
+    /// The Godot constructor for the CopperCloud class.
+    ///
+    /// # Arguments
+    /// * `base` - The base node of the CopperCloud.
+    ///
+    /// # Returns
+    /// * `CopperCloud` - A new instance of the CopperCloud class.
+    fn init(base: Base<Node2D>) -> Self {
+        Self { base, radius: 0.0 }
+    }
+
+    /// This is a built in method for Godot that is called when the node needs to be redrawn.
+    /// Nothing is drawn while the cloud is inactive.
+    fn draw(&mut self) {
+        if self.radius <= 0.0 {
+            return;
+        }
+
+        let radius = self.radius;
+        let mut base = self.base_mut();
+        base.draw_circle(Vector2::ZERO, radius, CLOUD_FILL_COLOR);
+        base.draw_arc_ex(
+            Vector2::ZERO,
+            radius,
+            0.0,
+            std::f32::consts::TAU,
+            CLOUD_EDGE_POINTS,
+            CLOUD_EDGE_COLOR,
+        )
+        .width(2.0)
+        .done();
+    }
+}
+
+#[godot_api]
+impl CopperCloud {
+    /// Sets the radius of the cloud and queues a redraw if it changed.
+    ///
+    /// # Arguments
+    /// * `radius` - The new radius of the cloud, 0 hides the cloud.
+    #[func]
+    pub fn set_radius(&mut self, radius: f32) {
+        if self.radius == radius {
+            return;
+        }
+
+        self.radius = radius;
+        self.base_mut().queue_redraw();
+    }
+
+    /// Gets the radius of the cloud.
+    ///
+    /// # Returns
+    /// * `f32` - The radius of the cloud, 0 if the cloud is not active.
+    #[func]
+    pub fn get_radius(&self) -> f32 {
+        self.radius
+    }
+}
@@ -0,0 +1,87 @@
+//! damage_event.rs
+//!
+//! This file defines the `DamageEvent` struct which describes a single instance of damage dealt to
+//! a player. Every source of damage builds a DamageEvent and hands it to the DamageResolver.
+//! It also defines the `DamageRecord` struct that players keep a short history of.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use std::time::Instant;
+
+use godot::prelude::*;
+
+use super::enums::attack_kind::AttackKind;
+use super::enums::damage_type::DamageType;
+use super::player::Player;
+
+/// A single instance of damage dealt to a player.
+#[derive(Clone)]
+pub struct DamageEvent {
+    /// The player responsible for the damage, if any.
+    pub source: Option<Gd<Player>>,
+    /// How the damage was dealt.
+    pub damage_type: DamageType,
+    /// The amount of damage before any modifiers are applied.
+    pub amount: f64,
+    /// The impulse applied to the damaged player.
+    pub knockback: Vector2,
+    /// The melee attack that dealt the damage, if any.
+    pub attack: Option<AttackKind>,
+}
+
+impl DamageEvent {
+    /// Creates a new damage event.
+    ///
+    /// # Arguments
+    /// * `source` - The player responsible for the damage, if any.
+    /// * `damage_type` - How the damage was dealt.
+    /// * `amount` - The amount of damage before any modifiers are applied.
+    /// * `knockback` - The impulse applied to the damaged player.
+    ///
+    /// # Returns
+    /// * `DamageEvent` - The new damage event.
+    pub fn new(
+        source: Option<Gd<Player>>,
+        damage_type: DamageType,
+        amount: f64,
+        knockback: Vector2,
+    ) -> Self {
+        Self {
+            source,
+            damage_type,
+            amount,
+            knockback,
+            attack: None,
+        }
+    }
+
+    /// Marks the damage as dealt by a melee attack.
+    ///
+    /// # Arguments
+    /// * `attack` - The melee attack that dealt the damage.
+    ///
+    /// # Returns
+    /// * `DamageEvent` - The damage event with its attack set.
+    pub fn with_attack(mut self, attack: AttackKind) -> Self {
+        self.attack = Some(attack);
+        self
+    }
+}
+
+/// A record of damage a player took from another player.
+/// Players keep a short history of these so kills and assists can be credited.
+#[derive(Clone)]
+pub struct DamageRecord {
+    /// The player that dealt the damage.
+    pub source: Gd<Player>,
+    /// The id of the player that dealt the damage.
+    pub source_id: i32,
+    /// How the damage was dealt.
+    pub damage_type: DamageType,
+    /// The melee attack that dealt the damage, if any.
+    pub attack: Option<AttackKind>,
+    /// The amount of damage dealt, 0 for contact such as being pushed into by a metal object.
+    pub amount: f64,
+    /// When the damage was dealt.
+    pub time: Instant,
+}
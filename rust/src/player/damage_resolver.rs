@@ -0,0 +1,441 @@
+//! damage_resolver.rs
+//!
+//! This file contains the DamageResolver which every DamageEvent passes through. The resolver
+//! checks the target's guard, applies damage modifiers, adjusts the target's health, applies
+//! knockback and hit-stun, records the damage in the target's damage history, credits
+//! eliminations and assists to the game and notifies the HUD and audio through the player's
+//! damage_taken signal.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use std::time::{Duration, Instant};
+
+use godot::prelude::*;
+
+use crate::game::Game;
+
+use super::attack_data::AttackData;
+use super::damage_event::{DamageEvent, DamageRecord};
+use super::enums::damage_type::DamageType;
+use super::enums::force::Force;
+use super::enums::guard_outcome::GuardOutcome;
+use super::enums::metal_type::MetalType;
+use super::enums::timeout_events::TimeoutEvents;
+use super::player::Player;
+
+/// The id sent with the damage_taken signal when the damage has no source player
+const NO_SOURCE_ID: i32 = -1;
+/// How long after dealing damage or pushing something into a player the source is credited
+/// with the kill if nobody else lands the killing blow
+const KILL_CREDIT_WINDOW: Duration = Duration::from_secs(5);
+/// How long after dealing damage a player is credited with an assist
+const ASSIST_WINDOW: Duration = Duration::from_secs(8);
+/// The knockback given to hits that come from a player but do not specify their own knockback
+const DEFAULT_KNOCKBACK: f32 = 150.0;
+/// The hit-stun every hit causes, before the damage of the hit is added
+const BASE_HIT_STUN: Duration = Duration::from_millis(150);
+/// The longest a single hit can stun a player for
+const MAX_HIT_STUN: Duration = Duration::from_millis(800);
+/// How much of the hit-stun is kept while the target is burning pewter
+const PEWTER_HIT_STUN_SCALE: f64 = 0.4;
+/// How much of a melee hit's damage gets through a block
+const BLOCK_DAMAGE_SCALE: f64 = 0.25;
+/// How much of a melee hit's knockback gets through a block
+const BLOCK_KNOCKBACK_SCALE: f32 = 0.5;
+/// The stamina drained from a blocking player for each point of damage they block
+const BLOCK_STAMINA_PER_DAMAGE: f64 = 1.0;
+/// The pewter drained from a blocking player burning pewter for each point of damage they block
+const BLOCK_PEWTER_PER_DAMAGE: f64 = 0.5;
+/// How long an attacker is stunned for when their attack is parried
+const PARRY_STUN: Duration = Duration::from_millis(700);
+
+/// Resolves damage events against a player.
+pub struct DamageResolver;
+
+impl DamageResolver {
+    /// Resolves a damage event against the target player.
+    ///
+    /// # Arguments
+    /// * `target` - The player being damaged.
+    /// * `event` - The damage being dealt.
+    ///
+    /// # Returns
+    /// * `f64` - The amount of damage that was actually dealt.
+    pub fn resolve(target: &mut Player, event: DamageEvent) -> f64 {
+        if target.get_health() <= 0.0
+            || (target.is_invulnerable()
+                && event.damage_type != DamageType::Environment
+                && !Self::is_combo_hit(target, &event))
+        {
+            return 0.0;
+        }
+
+        let guard = Self::check_guard(target, &event);
+        if guard == GuardOutcome::Parried {
+            if let Some(mut attacker) = event.source.clone() {
+                attacker.bind_mut().stun(PARRY_STUN);
+            }
+            return 0.0;
+        }
+
+        let source_id = Self::get_source_id(target, &event);
+        let mut amount = Self::apply_modifiers(target, &event);
+
+        // A broken guard lets the whole hit through
+        let blocked = guard == GuardOutcome::Blocked && !Self::drain_guard(target, amount);
+        if blocked {
+            amount *= BLOCK_DAMAGE_SCALE;
+        }
+
+        target.adjust_health(-amount);
+
+        if amount > 0.0 {
+            let mut knockback = Self::calculate_knockback(target, &event, amount);
+            if blocked {
+                knockback *= BLOCK_KNOCKBACK_SCALE;
+            }
+            if knockback != Vector2::ZERO {
+                target.add_force(Force::Knockback { impulse: knockback });
+            }
+
+            // Blocked hits push the player back without stunning them, and hard landings and wall
+            // slams put the player in the Stumble state instead
+            let stuns = !matches!(event.damage_type, DamageType::Fall | DamageType::Impact);
+            if !blocked && stuns {
+                let stun = Self::calculate_hit_stun(target, amount);
+                target.hurt(stun);
+            }
+        }
+
+        if let (Some(source), true) = (event.source.clone(), source_id != NO_SOURCE_ID) {
+            target.record_damage_source(source, source_id, event.damage_type, event.attack, amount);
+        }
+
+        let target_id = target.get_player_id();
+        let mut game = target.base().get_node_as::<Game>("/root/Game");
+        game.bind_mut().record_damage(
+            (source_id != NO_SOURCE_ID).then_some(source_id),
+            target_id,
+            amount,
+        );
+
+        target.base_mut().emit_signal(
+            "damage_taken",
+            &[
+                Variant::from(amount),
+                Variant::from(event.damage_type.as_str()),
+                Variant::from(source_id),
+            ],
+        );
+
+        if target.get_health() <= 0.0 {
+            Self::credit_elimination(target, game);
+        }
+
+        amount
+    }
+
+    /// Applies the target's damage modifiers to the damage of an event.
+    /// Pewter reduces damage, ignoring small hits entirely and lessening the effect of
+    /// large hits less. Environmental damage is never reduced.
+    ///
+    /// # Arguments
+    /// * `target` - The player being damaged.
+    /// * `event` - The damage being dealt.
+    ///
+    /// # Returns
+    /// * `f64` - The damage after modifiers are applied.
+    fn apply_modifiers(target: &mut Player, event: &DamageEvent) -> f64 {
+        let amount = event.amount.max(0.0);
+        let max_health = target.get_max_health();
+
+        if event.damage_type == DamageType::Environment
+            || !target.is_burning_metal(MetalType::Pewter)
+        {
+            return amount;
+        }
+
+        if amount <= max_health * 0.05 {
+            0.0
+        } else if amount <= max_health * 0.25 {
+            amount * 0.5
+        } else if amount <= max_health * 0.50 {
+            amount * 0.7
+        } else {
+            amount * 0.9
+        }
+    }
+
+    /// Calculates the knockback of a hit. Direct hits without their own knockback push the target
+    /// directly away from their source. Knockback grows with the damage dealt.
+    ///
+    /// # Arguments
+    /// * `target` - The player being damaged.
+    /// * `event` - The damage being dealt.
+    /// * `amount` - The damage after modifiers are applied.
+    ///
+    /// # Returns
+    /// * `Vector2` - The impulse to knock the target back with.
+    fn calculate_knockback(target: &Player, event: &DamageEvent, amount: f64) -> Vector2 {
+        let knockback = match event.source.as_ref() {
+            Some(source)
+                if event.knockback == Vector2::ZERO && !event.damage_type.is_environmental() =>
+            {
+                let away = target.base().get_global_position() - source.get_global_position();
+                away.normalized_or_zero() * DEFAULT_KNOCKBACK
+            }
+            _ => event.knockback,
+        };
+
+        knockback * (1.0 + (amount / target.get_max_health()) as f32)
+    }
+
+    /// Calculates how long a hit stuns the target. Bigger hits stun for longer and pewter lets
+    /// the target shake off the hit sooner.
+    ///
+    /// # Arguments
+    /// * `target` - The player being damaged.
+    /// * `amount` - The damage after modifiers are applied.
+    ///
+    /// # Returns
+    /// * `Duration` - How long the target is stunned for.
+    fn calculate_hit_stun(target: &mut Player, amount: f64) -> Duration {
+        let damage_scale = amount / target.get_max_health();
+        let mut stun = BASE_HIT_STUN.as_secs_f64() + MAX_HIT_STUN.as_secs_f64() * damage_scale;
+
+        if target.is_burning_metal(MetalType::Pewter) {
+            stun *= PEWTER_HIT_STUN_SCALE;
+        }
+
+        Duration::from_secs_f64(stun.min(MAX_HIT_STUN.as_secs_f64()))
+    }
+
+    /// Checks how the target's guard handles a hit. Only melee hits from a player the target is
+    /// facing can be blocked or parried.
+    ///
+    /// # Arguments
+    /// * `target` - The player being damaged.
+    /// * `event` - The damage being dealt.
+    ///
+    /// # Returns
+    /// * `GuardOutcome` - How the target's guard handled the hit.
+    fn check_guard(target: &Player, event: &DamageEvent) -> GuardOutcome {
+        let Some(source) = event.source.as_ref() else {
+            return GuardOutcome::Unguarded;
+        };
+
+        let to_source = source.get_global_position().x - target.base().get_global_position().x;
+        let facing_source = to_source.signum() == target.get_dir().signum();
+
+        if event.damage_type != DamageType::Melee
+            || !target.is_blocking()
+            || !facing_source
+            || source.instance_id() == target.base().instance_id()
+        {
+            GuardOutcome::Unguarded
+        } else if target.is_parrying() {
+            GuardOutcome::Parried
+        } else {
+            GuardOutcome::Blocked
+        }
+    }
+
+    /// Drains the cost of blocking a hit from the target. Players burning pewter pay with pewter
+    /// instead of stamina. If the target runs out of stamina their guard breaks and they can not
+    /// block again until the GuardBroken timeout ends, even though their stamina regenerates.
+    ///
+    /// # Arguments
+    /// * `target` - The player blocking the hit.
+    /// * `amount` - The damage of the hit before it is reduced by the block.
+    ///
+    /// # Returns
+    /// * `bool` - True if the target's guard broke.
+    fn drain_guard(target: &mut Player, amount: f64) -> bool {
+        if target.is_burning_metal(MetalType::Pewter) {
+            target.adjust_metals(
+                vec![MetalType::Pewter.as_str()],
+                -amount * BLOCK_PEWTER_PER_DAMAGE,
+            );
+            return false;
+        }
+
+        target.drain_stamina(amount * BLOCK_STAMINA_PER_DAMAGE);
+        if !target.is_fatigued() {
+            return false;
+        }
+
+        target.add_timeout_event(TimeoutEvents::GuardBroken);
+        true
+    }
+
+    /// Checks if a hit continues a melee combo, which lands through the target's invulnerability.
+    /// A hit is part of a combo when the attacker chained into it from the attack that last hit
+    /// the target during that attack's cancel window.
+    ///
+    /// # Arguments
+    /// * `target` - The player being damaged.
+    /// * `event` - The damage being dealt.
+    ///
+    /// # Returns
+    /// * `bool` - True if the hit continues a combo.
+    fn is_combo_hit(target: &Player, event: &DamageEvent) -> bool {
+        let (Some(source), Some(attack), Some(last_hit)) = (
+            event.source.as_ref(),
+            event.attack,
+            target.get_damage_history().back(),
+        ) else {
+            return false;
+        };
+        let Some(last_attack) = last_hit.attack else {
+            return false;
+        };
+
+        if last_hit.source.instance_id() != source.instance_id()
+            || AttackData::for_kind(last_attack).next != Some(attack)
+        {
+            return false;
+        }
+
+        // The last hit has to come from the link the attacker just chained out of, not an
+        // earlier combo
+        let source = source.bind();
+        source.get_combo_link() == Some(last_attack)
+            && last_hit.time.elapsed()
+                <= source.get_attack_elapsed() + AttackData::for_kind(last_attack).duration
+    }
+
+    /// Gets the player id of the source of a damage event.
+    /// Damage a player deals to themselves is treated as having no source.
+    ///
+    /// # Arguments
+    /// * `target` - The player being damaged.
+    /// * `event` - The damage being dealt.
+    ///
+    /// # Returns
+    /// * `i32` - The id of the source player or NO_SOURCE_ID.
+    fn get_source_id(target: &Player, event: &DamageEvent) -> i32 {
+        match event.source.as_ref() {
+            Some(source) if source.instance_id() != target.base().instance_id() => {
+                source.bind().get_player_id()
+            }
+            _ => NO_SOURCE_ID,
+        }
+    }
+
+    /// Credits the elimination of the target.
+    /// Players that left the game are not credited.
+    ///
+    /// # Arguments
+    /// * `target` - The player that was eliminated.
+    /// * `game` - The game to record the elimination in.
+    fn credit_elimination(target: &Player, mut game: Gd<Game>) {
+        let history: Vec<&DamageRecord> = target
+            .get_damage_history()
+            .iter()
+            .filter(|record| record.source.is_instance_valid())
+            .collect();
+        let credits: Vec<(i32, f64, Instant)> = history
+            .iter()
+            .map(|record| (record.source_id, record.amount, record.time))
+            .collect();
+
+        let Some((killer_id, assist_ids)) = Self::find_credit(&credits, Instant::now()) else {
+            game.bind_mut().record_death(target.get_player_id());
+            return;
+        };
+
+        if let Some(killer) = history.iter().find(|record| record.source_id == killer_id) {
+            killer.source.clone().bind_mut().increment_eliminations();
+        }
+        game.bind_mut()
+            .record_elimination(killer_id, &assist_ids, target.get_player_id());
+    }
+
+    /// Finds who gets credit for an elimination.
+    /// The most recent player in the target's damage history within the kill credit window gets
+    /// the kill. This includes players that pushed something into the target and players
+    /// responsible for fall, impact or environmental damage. Every other player that dealt damage
+    /// within the assist window gets an assist.
+    ///
+    /// # Arguments
+    /// * `history` - The source id, damage and time of each damage record, oldest first.
+    /// * `now` - When the target was eliminated.
+    ///
+    /// # Returns
+    /// * `Option<(i32, Vec<i32>)>` - The id of the killer and the ids of the assisting players, or
+    ///   None if nobody gets the kill.
+    fn find_credit(history: &[(i32, f64, Instant)], now: Instant) -> Option<(i32, Vec<i32>)> {
+        let (killer_id, _, _) = history
+            .iter()
+            .rev()
+            .find(|(_, _, time)| now.duration_since(*time) <= KILL_CREDIT_WINDOW)?;
+
+        let mut assist_ids: Vec<i32> = Vec::new();
+        for (source_id, amount, time) in history.iter() {
+            if source_id != killer_id
+                && *amount > 0.0
+                && now.duration_since(*time) <= ASSIST_WINDOW
+                && !assist_ids.contains(source_id)
+            {
+                assist_ids.push(*source_id);
+            }
+        }
+
+        Some((*killer_id, assist_ids))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Gets the time a number of seconds after a start time
+    fn after(start: Instant, secs: u64) -> Instant {
+        start + Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn pusher_gets_kill_from_environmental_damage() {
+        let start = Instant::now();
+        // Player 2 pushes a coin into the target and the target then slams into a wall
+        let history = [(2, 0.0, start), (2, 30.0, after(start, 1))];
+
+        assert_eq!(
+            DamageResolver::find_credit(&history, after(start, 1)),
+            Some((2, Vec::new()))
+        );
+    }
+
+    #[test]
+    fn pusher_without_damage_gets_kill() {
+        let start = Instant::now();
+        let history = [(3, 20.0, start), (2, 0.0, after(start, 1))];
+
+        assert_eq!(
+            DamageResolver::find_credit(&history, after(start, 2)),
+            Some((2, vec![3]))
+        );
+    }
+
+    #[test]
+    fn contact_without_damage_is_not_an_assist() {
+        let start = Instant::now();
+        let history = [(3, 0.0, start), (2, 20.0, after(start, 1))];
+
+        assert_eq!(
+            DamageResolver::find_credit(&history, after(start, 2)),
+            Some((2, Vec::new()))
+        );
+    }
+
+    #[test]
+    fn old_damage_gets_no_credit() {
+        let start = Instant::now();
+        let history = [(2, 50.0, start)];
+
+        assert_eq!(
+            DamageResolver::find_credit(&history, after(start, 10)),
+            None
+        );
+    }
+}
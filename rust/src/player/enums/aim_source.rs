@@ -0,0 +1,15 @@
+//! aim_source.rs
+//!
+//! This file defines the `AimSource` enum which tracks how a player is aiming.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+
+/// The device a player last aimed with.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum AimSource {
+    /// The player is aiming with the right joystick of their controller.
+    Joystick,
+    /// The player is aiming with the mouse cursor.
+    Mouse,
+}
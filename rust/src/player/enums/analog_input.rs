@@ -0,0 +1,36 @@
+//! analog_input.rs
+//!
+//! This file defines the `AnalogInput` enum, the sticks and triggers whose response a player can
+//! adjust.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+
+/// A stick or pair of triggers on a controller.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum AnalogInput {
+    /// The left stick, used to move
+    Movement,
+    /// The right stick, used to aim
+    Aim,
+    /// The triggers, used to burn steel and iron
+    Triggers,
+}
+
+impl AnalogInput {
+    /// Converts a string to the corresponding analog input.
+    ///
+    /// # Arguments
+    /// * `input` - The name of the analog input.
+    ///
+    /// # Returns
+    /// * `Option<AnalogInput>` - The analog input, or None if the name is not one.
+    pub fn from_string(input: &str) -> Option<AnalogInput> {
+        match input {
+            "movement" => Some(AnalogInput::Movement),
+            "aim" => Some(AnalogInput::Aim),
+            "triggers" => Some(AnalogInput::Triggers),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! attack_kind.rs
+//!
+//! This file defines the `AttackKind` enum which identifies each melee attack a player can do.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+
+/// The melee attacks a player can do. The hitbox, damage, knockback and timing of each attack is
+/// defined by its `AttackData`.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum AttackKind {
+    /// The first hit of the ground combo
+    Jab,
+    /// The second hit of the ground combo
+    Cross,
+    /// The last hit of the ground combo
+    Finisher,
+    /// An attack above the player
+    Up,
+    /// An attack below the player while in the air that spikes players downwards
+    DownAir,
+    /// A lunging attack done out of a sprint
+    Dash,
+}
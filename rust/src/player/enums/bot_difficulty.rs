@@ -0,0 +1,61 @@
+//! bot_difficulty.rs
+//!
+//! This file defines the `BotDifficulty` enum, which sets how quickly a bot reacts and how well it
+//! aims.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use std::time::Duration;
+
+/// How hard a bot is to play against.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum BotDifficulty {
+    /// The bot is slow to react and often misses
+    Easy,
+    /// The bot reacts about as fast as a casual player
+    Normal,
+    /// The bot reacts almost instantly and rarely misses
+    Hard,
+}
+
+impl BotDifficulty {
+    /// Converts a string to the corresponding difficulty.
+    ///
+    /// # Arguments
+    /// * `difficulty` - The name of the difficulty.
+    ///
+    /// # Returns
+    /// * `Option<BotDifficulty>` - The difficulty, or None if the name is not a difficulty.
+    pub fn from_string(difficulty: &str) -> Option<BotDifficulty> {
+        match difficulty {
+            "easy" => Some(BotDifficulty::Easy),
+            "normal" => Some(BotDifficulty::Normal),
+            "hard" => Some(BotDifficulty::Hard),
+            _ => None,
+        }
+    }
+
+    /// Gets how long the bot takes to react to what is happening around it.
+    ///
+    /// # Returns
+    /// * `Duration` - The time between the bot's decisions.
+    pub fn reaction_time(&self) -> Duration {
+        match self {
+            BotDifficulty::Easy => Duration::from_millis(450),
+            BotDifficulty::Normal => Duration::from_millis(250),
+            BotDifficulty::Hard => Duration::from_millis(120),
+        }
+    }
+
+    /// Gets how far off the bot's aim can be.
+    ///
+    /// # Returns
+    /// * `f32` - The largest angle in radians the bot's aim is off by, either way.
+    pub fn aim_error(&self) -> f32 {
+        match self {
+            BotDifficulty::Easy => 25.0_f32.to_radians(),
+            BotDifficulty::Normal => 12.0_f32.to_radians(),
+            BotDifficulty::Hard => 4.0_f32.to_radians(),
+        }
+    }
+}
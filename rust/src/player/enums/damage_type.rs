@@ -0,0 +1,49 @@
+//! damage_type.rs
+//!
+//! This file defines the `DamageType` enum and its associated methods.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+
+/// The different ways a player can be damaged.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum DamageType {
+    /// Damage from another player's melee attack.
+    Melee,
+    /// Damage from a thrown or pushed coin.
+    Coin,
+    /// Damage from a metal object or player slamming into the player.
+    Impact,
+    /// Damage from landing too hard.
+    Fall,
+    /// Damage from the map or anything else without a clear source.
+    Environment,
+}
+
+impl DamageType {
+    /// Checks if the damage comes from the player's surroundings rather than a direct hit. The
+    /// source of this damage is the player that sent the damaged player into their surroundings.
+    ///
+    /// # Returns
+    /// * `bool` - True for fall, impact and environmental damage.
+    pub fn is_environmental(&self) -> bool {
+        matches!(
+            self,
+            DamageType::Impact | DamageType::Fall | DamageType::Environment
+        )
+    }
+
+    /// Converts a damage type to a string.
+    ///
+    /// # Returns
+    /// * `&str` - The string representation of the damage type.
+    pub fn as_str(&self) -> &str {
+        match self {
+            DamageType::Melee => "melee",
+            DamageType::Coin => "coin",
+            DamageType::Impact => "impact",
+            DamageType::Fall => "fall",
+            DamageType::Environment => "environment",
+        }
+    }
+}
@@ -55,6 +55,10 @@ pub enum Force {
     },
     /// A force applied to objects when they are hit by an impact
     Impact { acceleration: Vector2 },
+    /// An instant change in velocity applied to a player when they are damaged
+    Knockback { impulse: Vector2 },
+    /// An instant change in velocity applied to a player when they start a melee attack
+    Lunge { impulse: Vector2 },
 }
 
 /// These are modifiers which will be applied to incoming player forces before they are actually
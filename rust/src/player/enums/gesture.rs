@@ -0,0 +1,34 @@
+//! gesture.rs
+//!
+//! This file defines the `Gesture` enum, the ways a button can be used, and the `GestureOutput`
+//! enum, the events a gesture can trigger.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use std::time::Duration;
+
+use super::metal_type::{BurnType, MetalType};
+use super::player_events::PlayerEvents;
+
+/// A way of using a button that can be mapped to its own event.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Gesture {
+    /// The button is pressed and released quickly.
+    Tap,
+    /// The button is held down for at least the given duration. The event lasts until the button
+    /// is released.
+    Hold { duration: Duration },
+    /// The button is tapped twice in quick succession.
+    DoubleTap,
+    /// Two buttons are pressed at the same time. The event lasts until either button is released.
+    Chord,
+}
+
+/// The event triggered by a gesture.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GestureOutput {
+    /// A player event, as if its own button was pressed.
+    Player(PlayerEvents),
+    /// A metal event, as if the metal's button was pressed with or without the low burn modifier.
+    Metal(MetalType, BurnType),
+}
@@ -0,0 +1,18 @@
+//! guard_outcome.rs
+//!
+//! This file defines the `GuardOutcome` enum, the result of a hit against a player that may be
+//! blocking.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+
+/// How a player's guard handled a hit
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GuardOutcome {
+    /// The player was not guarding against the hit
+    Unguarded,
+    /// The player blocked the hit, reducing its damage and knockback
+    Blocked,
+    /// The player parried the hit, taking no damage and stunning the attacker
+    Parried,
+}
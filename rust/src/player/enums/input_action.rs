@@ -0,0 +1,45 @@
+//! input_action.rs
+//!
+//! This file defines the `InputAction` enum, the input map actions the input manager turns button
+//! presses into. Buttons are resolved to an action once through a lookup table so the rest of the
+//! input manager works with the action itself rather than its name.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use super::metal_type::MetalType;
+use super::player_events::PlayerEvents;
+
+/// An action a button can be bound to.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum InputAction {
+    /// An action that triggers a player event
+    Player(PlayerEvents),
+    /// An action that burns a metal
+    Metal(MetalType),
+}
+
+impl InputAction {
+    /// Converts the name of an input map action to the action.
+    ///
+    /// # Arguments
+    /// * `action` - The name of the action.
+    ///
+    /// # Returns
+    /// * `Option<InputAction>` - The action, or None if the input manager does not handle it.
+    pub fn from_string(action: &str) -> Option<InputAction> {
+        PlayerEvents::from_string(action)
+            .map(InputAction::Player)
+            .or_else(|| MetalType::from_string(action).map(InputAction::Metal))
+    }
+
+    /// Gets the name of the action in the input map.
+    ///
+    /// # Returns
+    /// * `&str` - The name of the action.
+    pub fn as_str(&self) -> &str {
+        match self {
+            InputAction::Player(event) => event.as_str(),
+            InputAction::Metal(metal) => metal.as_str(),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! input_binding.rs
+//!
+//! This file defines the `InputBinding` enum, a single button, trigger, key or mouse button that
+//! an action can be bound to. Bindings do not belong to a device, which lets a player's bindings be
+//! matched against whichever device the player is using.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use godot::classes::{
+    InputEvent, InputEventJoypadButton, InputEventJoypadMotion, InputEventKey,
+    InputEventMouseButton,
+};
+use godot::global::{JoyAxis, JoyButton, Key, MouseButton};
+use godot::obj::EngineEnum;
+use godot::prelude::*;
+
+/// Something an action can be bound to.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum InputBinding {
+    /// A button on a gamepad
+    JoypadButton(JoyButton),
+    /// A trigger on a gamepad
+    JoypadMotion(JoyAxis),
+    /// A key on the keyboard, by its physical position
+    Key(Key),
+    /// A button on the mouse
+    MouseButton(MouseButton),
+}
+
+impl InputBinding {
+    /// Gets the binding an input event would make.
+    /// The sticks can not be bound as they are used for moving and aiming.
+    ///
+    /// # Arguments
+    /// * `event` - The input event.
+    ///
+    /// # Returns
+    /// * `Option<InputBinding>` - The binding, or None if the event can not be bound.
+    pub fn from_event(event: &Gd<InputEvent>) -> Option<InputBinding> {
+        if let Ok(button) = event.clone().try_cast::<InputEventJoypadButton>() {
+            Some(InputBinding::JoypadButton(button.get_button_index()))
+        } else if let Ok(motion) = event.clone().try_cast::<InputEventJoypadMotion>() {
+            let axis = motion.get_axis();
+            (axis == JoyAxis::TRIGGER_LEFT || axis == JoyAxis::TRIGGER_RIGHT)
+                .then_some(InputBinding::JoypadMotion(axis))
+        } else if let Ok(key) = event.clone().try_cast::<InputEventKey>() {
+            let keycode = match key.get_physical_keycode() {
+                Key::NONE => key.get_keycode(),
+                keycode => keycode,
+            };
+            Some(InputBinding::Key(keycode))
+        } else if let Ok(button) = event.clone().try_cast::<InputEventMouseButton>() {
+            Some(InputBinding::MouseButton(button.get_button_index()))
+        } else {
+            None
+        }
+    }
+
+    /// Creates an input event for this binding, such as to show it in a menu.
+    ///
+    /// # Arguments
+    /// * `device_id` - The device the event comes from.
+    ///
+    /// # Returns
+    /// * `Gd<InputEvent>` - The input event.
+    pub fn to_event(&self, device_id: i32) -> Gd<InputEvent> {
+        let mut event: Gd<InputEvent> = match *self {
+            InputBinding::JoypadButton(button_index) => {
+                let mut event = InputEventJoypadButton::new_gd();
+                event.set_button_index(button_index);
+                event.upcast()
+            }
+            InputBinding::JoypadMotion(axis) => {
+                let mut event = InputEventJoypadMotion::new_gd();
+                event.set_axis(axis);
+                event.upcast()
+            }
+            InputBinding::Key(keycode) => {
+                let mut event = InputEventKey::new_gd();
+                event.set_physical_keycode(keycode);
+                event.upcast()
+            }
+            InputBinding::MouseButton(button_index) => {
+                let mut event = InputEventMouseButton::new_gd();
+                event.set_button_index(button_index);
+                event.upcast()
+            }
+        };
+        event.set_device(device_id);
+        event
+    }
+
+    /// Converts the binding into a dictionary in the same format the keybind menu saves in.
+    ///
+    /// # Returns
+    /// * `Dictionary` - A dictionary with the kind of binding as its only key.
+    pub fn to_dictionary(&self) -> Dictionary {
+        let mut dictionary = Dictionary::new();
+        match *self {
+            InputBinding::JoypadButton(button) => dictionary.set("JoypadButton", button.ord()),
+            InputBinding::JoypadMotion(axis) => dictionary.set("JoypadMotion", axis.ord()),
+            InputBinding::Key(key) => dictionary.set("Key", key.ord()),
+            InputBinding::MouseButton(button) => dictionary.set("MouseButton", button.ord()),
+        }
+        dictionary
+    }
+
+    /// Reads a binding from a dictionary made by `to_dictionary` or by the keybind menu.
+    ///
+    /// # Arguments
+    /// * `dictionary` - The dictionary to read.
+    ///
+    /// # Returns
+    /// * `Option<InputBinding>` - The binding, or None if the dictionary is not a binding.
+    pub fn from_dictionary(dictionary: &Dictionary) -> Option<InputBinding> {
+        let ord = |key: &str| {
+            dictionary
+                .get(key)
+                .and_then(|value| value.try_to::<i32>().ok())
+        };
+
+        if let Some(button) = ord("JoypadButton") {
+            JoyButton::try_from_ord(button).map(InputBinding::JoypadButton)
+        } else if let Some(axis) = ord("JoypadMotion") {
+            JoyAxis::try_from_ord(axis).map(InputBinding::JoypadMotion)
+        } else if let Some(key) = ord("Key").or_else(|| ord("InputEventKey")) {
+            Key::try_from_ord(key).map(InputBinding::Key)
+        } else if let Some(button) = ord("MouseButton") {
+            MouseButton::try_from_ord(button).map(InputBinding::MouseButton)
+        } else {
+            None
+        }
+    }
+}
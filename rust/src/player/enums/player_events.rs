@@ -5,6 +5,8 @@
 //! Author: Charles Barth, Michael Imerman
 //! Version: Spring 2025
 
+use std::time::Duration;
+
 use godot::prelude::*;
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
@@ -39,6 +41,9 @@ impl TriggerEvents {
             PlayerEvents::Die => TriggerEvents::OnPress,
             PlayerEvents::MetalWheel => TriggerEvents::OnPress,
             PlayerEvents::Throw => TriggerEvents::OnPress,
+            PlayerEvents::LockOn => TriggerEvents::OnPress,
+            PlayerEvents::CycleTarget => TriggerEvents::OnPress,
+            PlayerEvents::Block => TriggerEvents::OnPress,
         }
     }
 }
@@ -68,6 +73,12 @@ pub enum PlayerEvents {
     MetalWheel,
     /// The player has thrown a coin.
     Throw,
+    /// The player is holding the lock on button to keep their steel/iron target selected.
+    LockOn,
+    /// The player has switched to the next steel/iron target while locked on.
+    CycleTarget,
+    /// The player is holding the block button to guard against melee attacks.
+    Block,
 }
 
 /// Methods for the PlayerEvents
@@ -89,28 +100,55 @@ impl PlayerEvents {
             "die" => Some(PlayerEvents::Die),
             "metal_selector" => Some(PlayerEvents::MetalWheel),
             "throw" => Some(PlayerEvents::Throw),
+            "lock_on" => Some(PlayerEvents::LockOn),
+            "cycle_target" => Some(PlayerEvents::CycleTarget),
+            "block" => Some(PlayerEvents::Block),
             _ => None,
         }
     }
 
-    /// This determines the number of frames a player event should be held in a
-    /// vec of registered events before expiring and being removed.
-    /// -1 means the event should persist until the button is released
+    /// Converts the player event to the name of its input action.
+    ///
+    /// # Returns
+    /// * `&str` - The name of the input action.
+    pub fn as_str(&self) -> &str {
+        match self {
+            PlayerEvents::Jump => "jump",
+            PlayerEvents::Crouch => "crouch",
+            PlayerEvents::Roll => "roll",
+            PlayerEvents::Sprint => "sprint",
+            PlayerEvents::Attack => "attack",
+            PlayerEvents::LowBurn => "low_burn",
+            PlayerEvents::Die => "die",
+            PlayerEvents::MetalWheel => "metal_selector",
+            PlayerEvents::Throw => "throw",
+            PlayerEvents::LockOn => "lock_on",
+            PlayerEvents::CycleTarget => "cycle_target",
+            PlayerEvents::Block => "block",
+        }
+    }
+
+    /// This determines how long a press of the player event is buffered for
+    /// before expiring and being removed, unless the settings override it.
+    /// None means the event should persist until the button is released.
+    /// These match the frame counts the events used to be buffered for at 60 Hz.
     ///
     /// # Returns
-    /// * `i8` - The number of frames the event should be held in a vec of
-    ///          registered events before expiring and being removed.
-    pub fn timeout(&self) -> i8 {
+    /// * `Option<Duration>` - How long a press of the event is buffered for.
+    pub fn default_buffer_window(&self) -> Option<Duration> {
         match self {
-            PlayerEvents::Jump => 12,
-            PlayerEvents::Crouch => 10,
-            PlayerEvents::Roll => 10,
-            PlayerEvents::Sprint => 10,
-            PlayerEvents::Attack => 10,
-            PlayerEvents::LowBurn => -1,
-            PlayerEvents::Die => 10,
-            PlayerEvents::MetalWheel => -1,
-            PlayerEvents::Throw => -1,
+            PlayerEvents::Jump => Some(Duration::from_millis(200)),
+            PlayerEvents::Crouch => Some(Duration::from_millis(167)),
+            PlayerEvents::Roll => Some(Duration::from_millis(167)),
+            PlayerEvents::Sprint => Some(Duration::from_millis(167)),
+            PlayerEvents::Attack => Some(Duration::from_millis(167)),
+            PlayerEvents::LowBurn => None,
+            PlayerEvents::Die => Some(Duration::from_millis(167)),
+            PlayerEvents::MetalWheel => None,
+            PlayerEvents::Throw => None,
+            PlayerEvents::LockOn => None,
+            PlayerEvents::CycleTarget => Some(Duration::from_millis(167)),
+            PlayerEvents::Block => None,
         }
     }
 }
@@ -7,10 +7,13 @@
 use crate::player::{
     player::Player,
     player_states::{
-        attack::Attack, crouch::Crouch, crouch_end::CrouchEnd, crouch_start::CrouchStart,
-        fall::Fall, idle::Idle, jump::Jump, land::Land, roll::Roll, run::Run, slide::Slide,
-        slide_crouch::SlideCrouch, sprint::Sprint,
+        attack::Attack, block::Block, crouch::Crouch, crouch_end::CrouchEnd,
+        crouch_start::CrouchStart, fall::Fall, hurt::Hurt, idle::Idle, jump::Jump, land::Land,
+        ledge_climb::LedgeClimb, ledge_grab::LedgeGrab, roll::Roll, run::Run, slide::Slide,
+        slide_crouch::SlideCrouch, sprint::Sprint, stumble::Stumble, wall_jump::WallJump,
+        wall_slide::WallSlide,
     },
+    state_transitions::StateTransitions,
     traits::player_state::PlayerState,
 };
 
@@ -31,6 +34,13 @@ pub enum PlayerStates {
     Sprint,
     SlideCrouch,
     Attack,
+    Hurt,
+    Block,
+    Stumble,
+    WallSlide,
+    WallJump,
+    LedgeGrab,
+    LedgeClimb,
 }
 
 /// Methods for the PlayerStates
@@ -55,14 +65,30 @@ impl PlayerStates {
             PlayerStates::Sprint => "run",
             PlayerStates::SlideCrouch => "slide",
             PlayerStates::Attack => "attack",
+            PlayerStates::Hurt => "hit",
+            // There is no block animation yet so the player braces in a crouch
+            PlayerStates::Block => "crouch_start",
+            PlayerStates::Stumble => "hit",
+            PlayerStates::WallSlide => "wall_slide",
+            PlayerStates::WallJump => "jump",
+            PlayerStates::LedgeGrab => "wall_hang",
+            PlayerStates::LedgeClimb => "clamber",
         }
     }
 
-    /// Calls the update function for the current state.
+    /// Takes the first transition out of the current state from the transition table whose
+    /// conditions hold. If no transition is taken the update function for the current state is
+    /// called instead.
     ///
     /// # Arguments
     /// * `player` - A mutable reference to the player so that the state can be updated.
     pub fn update_state(self, player: &mut Player) {
+        if let Some(transition) = StateTransitions::next_transition(self, player) {
+            player.set_transition_trigger(transition.trigger());
+            player.set_state(transition.to);
+            return;
+        }
+
         match self {
             PlayerStates::Idle => Idle::update(player),
             PlayerStates::Run => Run::update(player),
@@ -77,6 +103,13 @@ impl PlayerStates {
             PlayerStates::SlideCrouch => SlideCrouch::update(player),
             PlayerStates::Sprint => Sprint::update(player),
             PlayerStates::Attack => Attack::update(player),
+            PlayerStates::Hurt => Hurt::update(player),
+            PlayerStates::Block => Block::update(player),
+            PlayerStates::Stumble => Stumble::update(player),
+            PlayerStates::WallSlide => WallSlide::update(player),
+            PlayerStates::WallJump => WallJump::update(player),
+            PlayerStates::LedgeGrab => LedgeGrab::update(player),
+            PlayerStates::LedgeClimb => LedgeClimb::update(player),
         }
     }
 
@@ -99,6 +132,74 @@ impl PlayerStates {
             PlayerStates::SlideCrouch => SlideCrouch::enter(player),
             PlayerStates::Sprint => Sprint::enter(player),
             PlayerStates::Attack => Attack::enter(player),
+            PlayerStates::Hurt => Hurt::enter(player),
+            PlayerStates::Block => Block::enter(player),
+            PlayerStates::Stumble => Stumble::enter(player),
+            PlayerStates::WallSlide => WallSlide::enter(player),
+            PlayerStates::WallJump => WallJump::enter(player),
+            PlayerStates::LedgeGrab => LedgeGrab::enter(player),
+            PlayerStates::LedgeClimb => LedgeClimb::enter(player),
+        }
+    }
+
+    /// Calls the exit function for the current state.
+    ///
+    /// # Arguments
+    /// * `player` - A mutable reference to the player so that the state can be updated.
+    pub fn exit_state(self, player: &mut Player) {
+        match self {
+            PlayerStates::Idle => Idle::exit(player),
+            PlayerStates::Run => Run::exit(player),
+            PlayerStates::Jump => Jump::exit(player),
+            PlayerStates::Fall => Fall::exit(player),
+            PlayerStates::Land => Land::exit(player),
+            PlayerStates::Roll => Roll::exit(player),
+            PlayerStates::Crouch => Crouch::exit(player),
+            PlayerStates::CrouchEnd => CrouchEnd::exit(player),
+            PlayerStates::CrouchStart => CrouchStart::exit(player),
+            PlayerStates::Slide => Slide::exit(player),
+            PlayerStates::SlideCrouch => SlideCrouch::exit(player),
+            PlayerStates::Sprint => Sprint::exit(player),
+            PlayerStates::Attack => Attack::exit(player),
+            PlayerStates::Hurt => Hurt::exit(player),
+            PlayerStates::Block => Block::exit(player),
+            PlayerStates::Stumble => Stumble::exit(player),
+            PlayerStates::WallSlide => WallSlide::exit(player),
+            PlayerStates::WallJump => WallJump::exit(player),
+            PlayerStates::LedgeGrab => LedgeGrab::exit(player),
+            PlayerStates::LedgeClimb => LedgeClimb::exit(player),
+        }
+    }
+
+    /// Checks if the player can enter the state.
+    ///
+    /// # Arguments
+    /// * `player` - A reference to the player to check.
+    ///
+    /// # Returns
+    /// * `bool` - True if the player can enter the state.
+    pub fn can_enter_state(self, player: &Player) -> bool {
+        match self {
+            PlayerStates::Idle => Idle::can_enter(player),
+            PlayerStates::Run => Run::can_enter(player),
+            PlayerStates::Jump => Jump::can_enter(player),
+            PlayerStates::Fall => Fall::can_enter(player),
+            PlayerStates::Land => Land::can_enter(player),
+            PlayerStates::Roll => Roll::can_enter(player),
+            PlayerStates::Crouch => Crouch::can_enter(player),
+            PlayerStates::CrouchEnd => CrouchEnd::can_enter(player),
+            PlayerStates::CrouchStart => CrouchStart::can_enter(player),
+            PlayerStates::Slide => Slide::can_enter(player),
+            PlayerStates::SlideCrouch => SlideCrouch::can_enter(player),
+            PlayerStates::Sprint => Sprint::can_enter(player),
+            PlayerStates::Attack => Attack::can_enter(player),
+            PlayerStates::Hurt => Hurt::can_enter(player),
+            PlayerStates::Block => Block::can_enter(player),
+            PlayerStates::Stumble => Stumble::can_enter(player),
+            PlayerStates::WallSlide => WallSlide::can_enter(player),
+            PlayerStates::WallJump => WallJump::can_enter(player),
+            PlayerStates::LedgeGrab => LedgeGrab::can_enter(player),
+            PlayerStates::LedgeClimb => LedgeClimb::can_enter(player),
         }
     }
 }
@@ -0,0 +1,100 @@
+//! response_curve.rs
+//!
+//! This file defines the `ResponseCurve` enum, which shapes how far a stick or trigger has to be
+//! pushed for a given amount of input.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+
+/// How an analog input responds between its deadzones.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ResponseCurve {
+    /// The input grows evenly with how far the stick is pushed
+    Linear,
+    /// Small pushes give finer control and the input grows faster near the edge
+    Quadratic,
+    /// The input is raised to a chosen power. Powers above 1 give finer control near the center
+    /// and powers below 1 make the stick more sensitive.
+    Custom(f32),
+}
+
+impl ResponseCurve {
+    /// Converts a string to the corresponding response curve.
+    ///
+    /// # Arguments
+    /// * `curve` - The name of the curve.
+    /// * `exponent` - The power used by the custom curve.
+    ///
+    /// # Returns
+    /// * `Option<ResponseCurve>` - The curve, or None if the name is not a curve.
+    pub fn from_string(curve: &str, exponent: f32) -> Option<ResponseCurve> {
+        match curve {
+            "linear" => Some(ResponseCurve::Linear),
+            "quadratic" => Some(ResponseCurve::Quadratic),
+            "custom" if exponent > 0.0 => Some(ResponseCurve::Custom(exponent)),
+            _ => None,
+        }
+    }
+
+    /// Converts the response curve to a string.
+    ///
+    /// # Returns
+    /// * `&str` - The name of the curve.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ResponseCurve::Linear => "linear",
+            ResponseCurve::Quadratic => "quadratic",
+            ResponseCurve::Custom(_) => "custom",
+        }
+    }
+
+    /// Shapes an input with the curve.
+    ///
+    /// # Arguments
+    /// * `input` - How far the input is pushed past the inner deadzone, from 0 to 1.
+    ///
+    /// # Returns
+    /// * `f32` - The shaped input from 0 to 1.
+    pub fn apply(&self, input: f32) -> f32 {
+        match self {
+            ResponseCurve::Linear => input,
+            ResponseCurve::Quadratic => input * input,
+            ResponseCurve::Custom(exponent) => input.powf(*exponent),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn curves_keep_the_ends_fixed() {
+        for curve in [
+            ResponseCurve::Linear,
+            ResponseCurve::Quadratic,
+            ResponseCurve::Custom(0.5),
+        ] {
+            assert_eq!(curve.apply(0.0), 0.0);
+            assert_eq!(curve.apply(1.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn curves_shape_the_middle() {
+        assert_eq!(ResponseCurve::Linear.apply(0.5), 0.5);
+        assert_eq!(ResponseCurve::Quadratic.apply(0.5), 0.25);
+        assert!((ResponseCurve::Custom(3.0).apply(0.5) - 0.125).abs() < 1e-6);
+        assert!((ResponseCurve::Custom(0.5).apply(0.25) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn custom_curve_needs_a_positive_exponent() {
+        assert_eq!(
+            ResponseCurve::from_string("custom", 2.0),
+            Some(ResponseCurve::Custom(2.0))
+        );
+        assert_eq!(ResponseCurve::from_string("custom", 0.0), None);
+        assert_eq!(ResponseCurve::from_string("cubic", 3.0), None);
+    }
+}
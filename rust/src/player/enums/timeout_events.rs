@@ -11,6 +11,26 @@ use std::time::Duration;
 pub enum TimeoutEvents {
     /// A brief period of time where the player can jump after walking off a ledge
     CoyoteTime,
+    /// A brief period of time where the player can wall jump after sliding off a wall
+    WallCoyoteTime,
+    /// A period of exhaustion after the player runs out of pewter while exerting themselves
+    PewterDrag,
+    /// A period after being hit where the player can not be damaged again
+    Invulnerable,
+    /// A period after being hit where the player has no control, its duration depends on the hit
+    HitStun,
+    /// A brief window after the player starts blocking where hits against them are parried
+    ParryWindow,
+    /// A period after starting a block where the player can not open another parry window
+    ParryCooldown,
+    /// A period after the player's guard is broken where they can not block again
+    GuardBroken,
+    /// A period after a hard landing where the player has no control
+    Stumble,
+    /// A period after being pushed or pulled by steel or iron where hitting a wall hurts
+    MetalPropelled,
+    /// A brief period after letting go of a ledge where the player can not grab another one
+    LedgeRegrab,
 }
 
 impl TimeoutEvents {
@@ -21,6 +41,16 @@ impl TimeoutEvents {
     pub fn get_duration(&self) -> Duration {
         match self {
             TimeoutEvents::CoyoteTime => Duration::from_millis(100),
+            TimeoutEvents::WallCoyoteTime => Duration::from_millis(120),
+            TimeoutEvents::PewterDrag => Duration::from_millis(4000),
+            TimeoutEvents::Invulnerable => Duration::from_millis(600),
+            TimeoutEvents::HitStun => Duration::from_millis(250),
+            TimeoutEvents::ParryWindow => Duration::from_millis(100),
+            TimeoutEvents::ParryCooldown => Duration::from_millis(600),
+            TimeoutEvents::GuardBroken => Duration::from_millis(1500),
+            TimeoutEvents::Stumble => Duration::from_millis(500),
+            TimeoutEvents::MetalPropelled => Duration::from_millis(400),
+            TimeoutEvents::LedgeRegrab => Duration::from_millis(300),
         }
     }
 }
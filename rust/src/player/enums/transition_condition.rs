@@ -0,0 +1,158 @@
+//! transition_condition.rs
+//!
+//! This file defines the `TransitionCondition` enum, the conditions used by the player state
+//! transition table.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use godot::obj::WithBaseField;
+
+use crate::player::{
+    enums::{player_events::PlayerEvents, timeout_events::TimeoutEvents},
+    player::Player,
+    player_states::roll::ROLL_STAMINA_COST,
+};
+
+/// A condition that has to hold for the player to take a state transition.
+/// Conditions that fetch an event consume it when they pass, so they should be listed after any
+/// conditions that do not consume input.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TransitionCondition {
+    /// The player is able to jump and pressed jump, consuming the buffered press
+    JumpPressed,
+    /// The player is no longer holding jump
+    JumpReleased,
+    /// The player slid off a wall recently and pressed jump
+    WallCoyoteJump,
+    /// The player pressed attack
+    AttackPressed,
+    /// The player pressed crouch
+    CrouchPressed,
+    /// The player pressed roll
+    RollPressed,
+    /// The player pressed sprint
+    SprintPressed,
+    /// The player is holding block
+    BlockHeld,
+    /// The player is not holding block
+    BlockReleased,
+    /// The player is holding left or right
+    HorizontalInput,
+    /// The player is not holding left or right
+    NoHorizontalInput,
+    /// The player is not holding in the direction they are facing
+    NotHoldingForward,
+    /// The player is standing on the floor
+    Grounded,
+    /// The player is not standing on the floor
+    Airborne,
+    /// The player is falling in front of a ledge they can grab
+    LedgeInReach,
+    /// The player is falling while holding into a wall
+    PushingIntoWall,
+    /// The player has enough stamina to roll
+    HasRollStamina,
+    /// The player is out of stamina
+    Fatigued,
+    /// The player has stamina left
+    NotFatigued,
+    /// The animation of the current state has finished
+    AnimationFinished,
+}
+
+impl TransitionCondition {
+    /// Checks if the condition holds for the player.
+    ///
+    /// # Arguments
+    /// * `player` - The player to check the condition for.
+    ///
+    /// # Returns
+    /// * `bool` - True if the condition holds.
+    pub fn check(&self, player: &mut Player) -> bool {
+        match self {
+            TransitionCondition::JumpPressed => {
+                player.jump_available() && Self::fetch_event(player, PlayerEvents::Jump)
+            }
+            TransitionCondition::JumpReleased => !Self::check_event(player, PlayerEvents::Jump),
+            TransitionCondition::WallCoyoteJump => {
+                player.is_timeout_event_active(TimeoutEvents::WallCoyoteTime)
+                    && Self::fetch_event(player, PlayerEvents::Jump)
+            }
+            TransitionCondition::AttackPressed => Self::fetch_event(player, PlayerEvents::Attack),
+            TransitionCondition::CrouchPressed => Self::fetch_event(player, PlayerEvents::Crouch),
+            TransitionCondition::RollPressed => Self::fetch_event(player, PlayerEvents::Roll),
+            TransitionCondition::SprintPressed => Self::fetch_event(player, PlayerEvents::Sprint),
+            TransitionCondition::BlockHeld => Self::check_event(player, PlayerEvents::Block),
+            TransitionCondition::BlockReleased => !Self::check_event(player, PlayerEvents::Block),
+            TransitionCondition::HorizontalInput => player.get_horizontal_movement() != 0.0,
+            TransitionCondition::NoHorizontalInput => player.get_horizontal_movement() == 0.0,
+            TransitionCondition::NotHoldingForward => {
+                let horizontal_dir = player.get_horizontal_movement();
+                horizontal_dir == 0.0 || horizontal_dir.signum() != player.get_dir().signum()
+            }
+            TransitionCondition::Grounded => player.base().is_on_floor(),
+            TransitionCondition::Airborne => !player.base().is_on_floor(),
+            TransitionCondition::LedgeInReach => {
+                player.base().get_velocity().y >= 0.0 && player.detect_ledge()
+            }
+            TransitionCondition::PushingIntoWall => {
+                player.base().get_velocity().y >= 0.0 && player.is_pushing_into_wall()
+            }
+            TransitionCondition::HasRollStamina => player.has_stamina(ROLL_STAMINA_COST),
+            TransitionCondition::Fatigued => player.is_fatigued(),
+            TransitionCondition::NotFatigued => !player.is_fatigued(),
+            TransitionCondition::AnimationFinished => player.is_anim_finished(),
+        }
+    }
+
+    /// Gets the input event the condition checks for, if any.
+    /// This is recorded as the trigger of a transition in the player's state history.
+    ///
+    /// # Returns
+    /// * `Option<PlayerEvents>` - The event the condition checks for.
+    pub fn event(&self) -> Option<PlayerEvents> {
+        match self {
+            TransitionCondition::JumpPressed
+            | TransitionCondition::JumpReleased
+            | TransitionCondition::WallCoyoteJump => Some(PlayerEvents::Jump),
+            TransitionCondition::AttackPressed => Some(PlayerEvents::Attack),
+            TransitionCondition::CrouchPressed => Some(PlayerEvents::Crouch),
+            TransitionCondition::RollPressed => Some(PlayerEvents::Roll),
+            TransitionCondition::SprintPressed => Some(PlayerEvents::Sprint),
+            TransitionCondition::BlockHeld | TransitionCondition::BlockReleased => {
+                Some(PlayerEvents::Block)
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks if a player event is registered without consuming it.
+    ///
+    /// # Arguments
+    /// * `player` - The player whose input is checked.
+    /// * `event` - The event to check for.
+    ///
+    /// # Returns
+    /// * `bool` - True if the event is registered.
+    fn check_event(player: &mut Player, event: PlayerEvents) -> bool {
+        player
+            .get_input_manager()
+            .bind()
+            .check_for_player_event(event)
+    }
+
+    /// Checks if a player event is registered and consumes it if it is.
+    ///
+    /// # Arguments
+    /// * `player` - The player whose input is checked.
+    /// * `event` - The event to fetch.
+    ///
+    /// # Returns
+    /// * `bool` - True if the event was registered.
+    fn fetch_event(player: &mut Player, event: PlayerEvents) -> bool {
+        player
+            .get_input_manager()
+            .bind_mut()
+            .fetch_player_event(event)
+    }
+}
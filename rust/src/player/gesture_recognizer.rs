@@ -0,0 +1,551 @@
+//! gesture_recognizer.rs
+//!
+//! This file contains the GestureRecognizer which lets one button trigger different events
+//! depending on how it is used. A button can be tapped, held for a set amount of time, double
+//! tapped or pressed together with another button as a chord, and each of these can be bound to
+//! its own player or metal event.
+//!
+//! The recognizer is given the time of every press and release instead of reading the clock
+//! itself so it can be driven with synthetic input.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use super::enums::gesture::{Gesture, GestureOutput};
+use super::enums::metal_type::{BurnType, MetalType};
+
+/// The longest a button can be held for and still count as a tap when it has no hold binding
+const TAP_TIME: Duration = Duration::from_millis(250);
+/// How soon after a tap the second tap of a double tap has to be pressed
+const DOUBLE_TAP_WINDOW: Duration = Duration::from_millis(250);
+/// How close together the buttons of a chord have to be pressed
+const CHORD_WINDOW: Duration = Duration::from_millis(60);
+/// How long a metal button has to be held to burn the metal instead of low burning it
+const METAL_HOLD_TIME: Duration = Duration::from_millis(200);
+
+/// Binds a gesture on one or two buttons to an event.
+#[derive(Debug, Clone)]
+pub struct GestureBinding {
+    /// The input actions the gesture is made with. Chords use two actions, every other gesture
+    /// uses one.
+    pub buttons: Vec<String>,
+    /// How the buttons have to be used.
+    pub gesture: Gesture,
+    /// The event the gesture triggers.
+    pub output: GestureOutput,
+}
+
+impl GestureBinding {
+    /// Creates a binding for a gesture made with a single button.
+    ///
+    /// # Arguments
+    /// * `button` - The input action the gesture is made with.
+    /// * `gesture` - How the button has to be used.
+    /// * `output` - The event the gesture triggers.
+    ///
+    /// # Returns
+    /// * `GestureBinding` - The new binding.
+    pub fn new(button: &str, gesture: Gesture, output: GestureOutput) -> Self {
+        Self {
+            buttons: vec![button.to_string()],
+            gesture,
+            output,
+        }
+    }
+
+    /// Creates a binding for a chord of two buttons.
+    ///
+    /// # Arguments
+    /// * `first` - The first input action of the chord.
+    /// * `second` - The second input action of the chord.
+    /// * `output` - The event the chord triggers.
+    ///
+    /// # Returns
+    /// * `GestureBinding` - The new binding.
+    pub fn chord(first: &str, second: &str, output: GestureOutput) -> Self {
+        Self {
+            buttons: vec![first.to_string(), second.to_string()],
+            gesture: Gesture::Chord,
+            output,
+        }
+    }
+
+    /// Creates the bindings that let a metal's button both low burn and burn it. Tapping the
+    /// button toggles low burning and holding it burns the metal until it is released, so the
+    /// low burn modifier button is no longer needed.
+    ///
+    /// # Arguments
+    /// * `metal` - The metal to bind.
+    ///
+    /// # Returns
+    /// * `[GestureBinding; 2]` - The tap and hold bindings.
+    pub fn metal_tap_hold(metal: MetalType) -> [Self; 2] {
+        [
+            Self::new(
+                metal.as_str(),
+                Gesture::Tap,
+                GestureOutput::Metal(metal, BurnType::LowBurn),
+            ),
+            Self::new(
+                metal.as_str(),
+                Gesture::Hold {
+                    duration: METAL_HOLD_TIME,
+                },
+                GestureOutput::Metal(metal, BurnType::Burn),
+            ),
+        ]
+    }
+}
+
+/// A change to an event caused by a gesture.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GestureSignal {
+    /// The event starts, as if its button was pressed.
+    Start(GestureOutput),
+    /// The event ends, as if its button was released.
+    End(GestureOutput),
+}
+
+/// What the recognizer knows about a button that has gesture bindings.
+#[derive(Debug, Default, Clone)]
+struct ButtonGesture {
+    /// When the button was pressed, if it is held down.
+    pressed_at: Option<Instant>,
+    /// When the button was last tapped, if the tap is waiting to see if it becomes a double tap.
+    pending_tap: Option<Instant>,
+    /// The event started by holding the button or pressing it as part of a chord.
+    active: Option<GestureOutput>,
+    /// Whether the current press has already been used by a gesture and should not also be
+    /// treated as a tap when it is released.
+    used: bool,
+}
+
+/// Turns presses and releases of buttons with gesture bindings into events.
+#[derive(Debug, Default)]
+pub struct GestureRecognizer {
+    /// The gestures that are recognized.
+    bindings: Vec<GestureBinding>,
+    /// The state of every button that has a gesture binding.
+    buttons: HashMap<String, ButtonGesture>,
+}
+
+impl GestureRecognizer {
+    /// Adds a gesture binding.
+    ///
+    /// # Arguments
+    /// * `binding` - The binding to add.
+    pub fn add_binding(&mut self, binding: GestureBinding) {
+        for button in binding.buttons.iter() {
+            self.buttons.entry(button.clone()).or_default();
+        }
+        self.bindings.push(binding);
+    }
+
+    /// Removes every binding that uses a button.
+    ///
+    /// # Arguments
+    /// * `button` - The input action to remove the bindings of.
+    pub fn remove_bindings(&mut self, button: &str) {
+        self.bindings
+            .retain(|binding| !binding.buttons.iter().any(|bound| bound == button));
+
+        let bindings = &self.bindings;
+        self.buttons.retain(|button, _| {
+            bindings
+                .iter()
+                .any(|binding| binding.buttons.contains(button))
+        });
+    }
+
+    /// Forgets every button that is held or waiting on a second tap, keeping the bindings.
+    pub fn reset(&mut self) {
+        for button in self.buttons.values_mut() {
+            *button = ButtonGesture::default();
+        }
+    }
+
+    /// Checks if a button has any gesture bindings. Buttons without bindings are handled by the
+    /// input manager as usual.
+    ///
+    /// # Arguments
+    /// * `button` - The input action to check.
+    ///
+    /// # Returns
+    /// * `bool` - True if the button has gesture bindings.
+    pub fn handles(&self, button: &str) -> bool {
+        self.buttons.contains_key(button)
+    }
+
+    /// Handles a button being pressed. Pressing the second button of a chord starts the chord
+    /// and pressing a button again soon after tapping it is a double tap.
+    ///
+    /// # Arguments
+    /// * `button` - The input action that was pressed.
+    /// * `now` - When the button was pressed.
+    ///
+    /// # Returns
+    /// * `Vec<GestureSignal>` - The events started or ended by the press.
+    pub fn press(&mut self, button: &str, now: Instant) -> Vec<GestureSignal> {
+        let mut signals = Vec::new();
+        if !self.handles(button) {
+            return signals;
+        }
+
+        if let Some(state) = self.buttons.get_mut(button) {
+            state.pressed_at = Some(now);
+            state.used = false;
+        }
+
+        if let Some((partner, output)) = self.find_chord(button, now) {
+            for chord_button in [button, partner.as_str()] {
+                if let Some(state) = self.buttons.get_mut(chord_button) {
+                    state.active = Some(output);
+                    state.used = true;
+                    state.pending_tap = None;
+                }
+            }
+            signals.push(GestureSignal::Start(output));
+            return signals;
+        }
+
+        let double_tap = self.find_binding(button, |gesture| gesture == Gesture::DoubleTap);
+        let state = self.buttons.get_mut(button).expect("button has bindings");
+
+        if let (Some(output), Some(tapped_at)) = (double_tap, state.pending_tap) {
+            if now.duration_since(tapped_at) <= DOUBLE_TAP_WINDOW {
+                state.pending_tap = None;
+                state.used = true;
+                signals.push(GestureSignal::Start(output));
+                signals.push(GestureSignal::End(output));
+            }
+        }
+
+        signals
+    }
+
+    /// Handles a button being released. Releasing a button ends its hold or chord, and releasing
+    /// it quickly is a tap. Taps on buttons that can also be double tapped wait to see if a second
+    /// tap follows.
+    ///
+    /// # Arguments
+    /// * `button` - The input action that was released.
+    /// * `now` - When the button was released.
+    ///
+    /// # Returns
+    /// * `Vec<GestureSignal>` - The events started or ended by the release.
+    pub fn release(&mut self, button: &str, now: Instant) -> Vec<GestureSignal> {
+        let mut signals = Vec::new();
+        if !self.handles(button) {
+            return signals;
+        }
+
+        let tap = self.find_binding(button, |gesture| gesture == Gesture::Tap);
+        let has_hold = self
+            .find_binding(button, |gesture| matches!(gesture, Gesture::Hold { .. }))
+            .is_some();
+        let has_double_tap = self
+            .find_binding(button, |gesture| gesture == Gesture::DoubleTap)
+            .is_some();
+
+        let state = self.buttons.get_mut(button).expect("button has bindings");
+        let Some(pressed_at) = state.pressed_at.take() else {
+            return signals;
+        };
+
+        if let Some(output) = state.active.take() {
+            signals.push(GestureSignal::End(output));
+
+            // Letting go of either button ends a chord for both of them
+            if let Some(partner) = self.chord_partner(button, output) {
+                if let Some(partner_state) = self.buttons.get_mut(&partner) {
+                    partner_state.active = None;
+                }
+            }
+            return signals;
+        }
+
+        let quick = has_hold || now.duration_since(pressed_at) <= TAP_TIME;
+        if state.used || !quick {
+            return signals;
+        }
+
+        if has_double_tap {
+            state.pending_tap = Some(now);
+        } else if let Some(output) = tap {
+            signals.push(GestureSignal::Start(output));
+            signals.push(GestureSignal::End(output));
+        }
+
+        signals
+    }
+
+    /// Starts holds that have been held long enough and sends taps that were not followed by a
+    /// second tap. This should be called every frame.
+    ///
+    /// # Arguments
+    /// * `now` - The current time.
+    ///
+    /// # Returns
+    /// * `Vec<GestureSignal>` - The events started by held buttons and finished taps.
+    pub fn update(&mut self, now: Instant) -> Vec<GestureSignal> {
+        let mut signals = Vec::new();
+
+        for binding in self.bindings.iter() {
+            let [button] = binding.buttons.as_slice() else {
+                continue;
+            };
+            let Some(state) = self.buttons.get_mut(button) else {
+                continue;
+            };
+
+            match binding.gesture {
+                Gesture::Hold { duration } => {
+                    let Some(pressed_at) = state.pressed_at else {
+                        continue;
+                    };
+                    if !state.used && now.duration_since(pressed_at) >= duration {
+                        state.active = Some(binding.output);
+                        state.used = true;
+                        signals.push(GestureSignal::Start(binding.output));
+                    }
+                }
+                Gesture::Tap => {
+                    let Some(tapped_at) = state.pending_tap else {
+                        continue;
+                    };
+                    if now.duration_since(tapped_at) > DOUBLE_TAP_WINDOW {
+                        state.pending_tap = None;
+                        signals.push(GestureSignal::Start(binding.output));
+                        signals.push(GestureSignal::End(binding.output));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Taps on buttons without a tap binding are dropped once they can no longer be double taps
+        for state in self.buttons.values_mut() {
+            if state
+                .pending_tap
+                .is_some_and(|tapped_at| now.duration_since(tapped_at) > DOUBLE_TAP_WINDOW)
+            {
+                state.pending_tap = None;
+            }
+        }
+
+        signals
+    }
+
+    /// Finds the output of a single button binding whose gesture matches.
+    ///
+    /// # Arguments
+    /// * `button` - The input action of the binding.
+    /// * `matches` - Checks if the gesture of a binding is the one being looked for.
+    ///
+    /// # Returns
+    /// * `Option<GestureOutput>` - The output of the first matching binding.
+    fn find_binding(
+        &self,
+        button: &str,
+        matches: impl Fn(Gesture) -> bool,
+    ) -> Option<GestureOutput> {
+        self.bindings
+            .iter()
+            .find(|binding| binding.buttons == [button] && matches(binding.gesture))
+            .map(|binding| binding.output)
+    }
+
+    /// Finds a chord completed by pressing a button. The other button of the chord has to have
+    /// been pressed just before and not already be part of another gesture.
+    ///
+    /// # Arguments
+    /// * `button` - The input action that was pressed.
+    /// * `now` - When the button was pressed.
+    ///
+    /// # Returns
+    /// * `Option<(String, GestureOutput)>` - The other button of the chord and its output.
+    fn find_chord(&self, button: &str, now: Instant) -> Option<(String, GestureOutput)> {
+        self.bindings
+            .iter()
+            .filter(|binding| binding.gesture == Gesture::Chord)
+            .find_map(|binding| {
+                let partner = binding.buttons.iter().find(|bound| *bound != button)?;
+                if !binding.buttons.iter().any(|bound| bound == button) {
+                    return None;
+                }
+
+                let partner_state = self.buttons.get(partner)?;
+                let pressed_at = partner_state.pressed_at?;
+                let in_time = now.duration_since(pressed_at) <= CHORD_WINDOW;
+
+                (in_time && !partner_state.used).then(|| (partner.clone(), binding.output))
+            })
+    }
+
+    /// Finds the other button of an active chord.
+    ///
+    /// # Arguments
+    /// * `button` - One button of the chord.
+    /// * `output` - The output of the chord.
+    ///
+    /// # Returns
+    /// * `Option<String>` - The other button of the chord, if the output came from a chord.
+    fn chord_partner(&self, button: &str, output: GestureOutput) -> Option<String> {
+        self.bindings
+            .iter()
+            .filter(|binding| binding.gesture == Gesture::Chord && binding.output == output)
+            .filter(|binding| binding.buttons.iter().any(|bound| bound == button))
+            .find_map(|binding| binding.buttons.iter().find(|bound| *bound != button))
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::enums::player_events::PlayerEvents;
+
+    const JUMP: GestureOutput = GestureOutput::Player(PlayerEvents::Jump);
+    const ROLL: GestureOutput = GestureOutput::Player(PlayerEvents::Roll);
+    const SPRINT: GestureOutput = GestureOutput::Player(PlayerEvents::Sprint);
+
+    /// Gets the time a number of milliseconds after a start time
+    fn after(start: Instant, ms: u64) -> Instant {
+        start + Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn quick_release_is_a_tap() {
+        let mut recognizer = GestureRecognizer::default();
+        recognizer.add_binding(GestureBinding::new("a", Gesture::Tap, JUMP));
+        let start = Instant::now();
+
+        assert!(recognizer.press("a", start).is_empty());
+        assert_eq!(
+            recognizer.release("a", after(start, 100)),
+            vec![GestureSignal::Start(JUMP), GestureSignal::End(JUMP)]
+        );
+    }
+
+    #[test]
+    fn slow_release_is_not_a_tap() {
+        let mut recognizer = GestureRecognizer::default();
+        recognizer.add_binding(GestureBinding::new("a", Gesture::Tap, JUMP));
+        let start = Instant::now();
+
+        recognizer.press("a", start);
+        assert!(recognizer.release("a", after(start, 300)).is_empty());
+    }
+
+    #[test]
+    fn hold_starts_after_duration_and_ends_on_release() {
+        let mut recognizer = GestureRecognizer::default();
+        let hold = Gesture::Hold {
+            duration: Duration::from_millis(200),
+        };
+        recognizer.add_binding(GestureBinding::new("a", hold, SPRINT));
+        let start = Instant::now();
+
+        recognizer.press("a", start);
+        assert!(recognizer.update(after(start, 100)).is_empty());
+        assert_eq!(
+            recognizer.update(after(start, 250)),
+            vec![GestureSignal::Start(SPRINT)]
+        );
+        assert!(recognizer.update(after(start, 300)).is_empty());
+        assert_eq!(
+            recognizer.release("a", after(start, 400)),
+            vec![GestureSignal::End(SPRINT)]
+        );
+    }
+
+    #[test]
+    fn metal_button_taps_to_low_burn_and_holds_to_burn() {
+        let mut recognizer = GestureRecognizer::default();
+        for binding in GestureBinding::metal_tap_hold(MetalType::Steel) {
+            recognizer.add_binding(binding);
+        }
+        let low_burn = GestureOutput::Metal(MetalType::Steel, BurnType::LowBurn);
+        let burn = GestureOutput::Metal(MetalType::Steel, BurnType::Burn);
+        let start = Instant::now();
+
+        recognizer.press("steel", start);
+        assert_eq!(
+            recognizer.release("steel", after(start, 100)),
+            vec![GestureSignal::Start(low_burn), GestureSignal::End(low_burn)]
+        );
+
+        recognizer.press("steel", after(start, 500));
+        assert_eq!(
+            recognizer.update(after(start, 750)),
+            vec![GestureSignal::Start(burn)]
+        );
+        assert_eq!(
+            recognizer.release("steel", after(start, 800)),
+            vec![GestureSignal::End(burn)]
+        );
+    }
+
+    #[test]
+    fn tap_waits_for_double_tap_window() {
+        let mut recognizer = GestureRecognizer::default();
+        recognizer.add_binding(GestureBinding::new("a", Gesture::Tap, JUMP));
+        recognizer.add_binding(GestureBinding::new("a", Gesture::DoubleTap, ROLL));
+        let start = Instant::now();
+
+        recognizer.press("a", start);
+        assert!(recognizer.release("a", after(start, 50)).is_empty());
+        assert!(recognizer.update(after(start, 200)).is_empty());
+        assert_eq!(
+            recognizer.update(after(start, 400)),
+            vec![GestureSignal::Start(JUMP), GestureSignal::End(JUMP)]
+        );
+    }
+
+    #[test]
+    fn second_tap_in_window_is_a_double_tap() {
+        let mut recognizer = GestureRecognizer::default();
+        recognizer.add_binding(GestureBinding::new("a", Gesture::Tap, JUMP));
+        recognizer.add_binding(GestureBinding::new("a", Gesture::DoubleTap, ROLL));
+        let start = Instant::now();
+
+        recognizer.press("a", start);
+        recognizer.release("a", after(start, 50));
+        assert_eq!(
+            recognizer.press("a", after(start, 150)),
+            vec![GestureSignal::Start(ROLL), GestureSignal::End(ROLL)]
+        );
+        assert!(recognizer.release("a", after(start, 200)).is_empty());
+        assert!(recognizer.update(after(start, 600)).is_empty());
+    }
+
+    #[test]
+    fn buttons_pressed_together_are_a_chord() {
+        let mut recognizer = GestureRecognizer::default();
+        recognizer.add_binding(GestureBinding::chord("a", "b", SPRINT));
+        let start = Instant::now();
+
+        assert!(recognizer.press("a", start).is_empty());
+        assert_eq!(
+            recognizer.press("b", after(start, 30)),
+            vec![GestureSignal::Start(SPRINT)]
+        );
+        assert_eq!(
+            recognizer.release("a", after(start, 200)),
+            vec![GestureSignal::End(SPRINT)]
+        );
+        assert!(recognizer.release("b", after(start, 250)).is_empty());
+    }
+
+    #[test]
+    fn buttons_pressed_apart_are_not_a_chord() {
+        let mut recognizer = GestureRecognizer::default();
+        recognizer.add_binding(GestureBinding::chord("a", "b", SPRINT));
+        let start = Instant::now();
+
+        recognizer.press("a", start);
+        assert!(recognizer.press("b", after(start, 100)).is_empty());
+    }
+}
@@ -0,0 +1,255 @@
+//! input_buffer.rs
+//!
+//! This file contains the InputBuffer, which keeps track of the player events a player has pressed,
+//! is holding and has released. Presses and releases are buffered for a short window so an input
+//! given slightly too early is not lost, and a press can only be fetched once.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use super::enums::player_events::PlayerEvents;
+
+/// A buffered press of a player event.
+#[derive(Clone, Copy)]
+struct BufferedPress {
+    /// When the button was pressed.
+    time: Instant,
+    /// Whether the press has already been fetched. A fetched press can only be checked for while
+    /// the button is still held, not fetched again.
+    consumed: bool,
+}
+
+/// The buffered presses and releases of a player's events.
+#[derive(Default)]
+pub struct InputBuffer {
+    /// The player events that have been pressed.
+    /// Presses are buffered until their buffer window runs out. Events without a buffer window
+    /// are removed when the button is released instead.
+    presses: HashMap<PlayerEvents, BufferedPress>,
+    /// The player events whose buttons are currently held down.
+    held: HashSet<PlayerEvents>,
+    /// The player events whose buttons have been released and when they were released. Releases
+    /// are buffered the same way presses are.
+    releases: HashMap<PlayerEvents, Instant>,
+}
+
+impl InputBuffer {
+    /// Buffers a press of an event and marks its button as held.
+    ///
+    /// # Arguments
+    /// * `event` - The event that was pressed.
+    /// * `now` - When the button was pressed.
+    pub fn press(&mut self, event: PlayerEvents, now: Instant) {
+        self.held.insert(event);
+        self.releases.remove(&event);
+        self.presses.insert(
+            event,
+            BufferedPress {
+                time: now,
+                consumed: false,
+            },
+        );
+    }
+
+    /// Buffers a release of an event. Presses of events with a buffer window stay buffered after
+    /// the button is released so an early press is not lost, while presses of events without one
+    /// and presses that have been fetched are removed.
+    ///
+    /// # Arguments
+    /// * `event` - The event that was released.
+    /// * `now` - When the button was released.
+    /// * `window` - The buffer window of the event, None if it lasts until the button is released.
+    pub fn release(&mut self, event: PlayerEvents, now: Instant, window: Option<Duration>) {
+        self.held.remove(&event);
+        self.releases.insert(event, now);
+
+        // Consumed presses are no longer needed once the button is let go
+        let consumed = self.presses.get(&event).is_some_and(|press| press.consumed);
+        if consumed || window.is_none() {
+            self.presses.remove(&event);
+        }
+    }
+
+    /// Fetches a buffered press of an event that has not been fetched yet. The press is still
+    /// buffered while the button is held so checking for the event keeps working.
+    ///
+    /// # Arguments
+    /// * `event` - The event to fetch.
+    ///
+    /// # Returns
+    /// * `bool` - True if an unfetched press of the event was buffered.
+    pub fn fetch(&mut self, event: PlayerEvents) -> bool {
+        let held = self.held.contains(&event);
+        let Some(press) = self.presses.get_mut(&event) else {
+            return false;
+        };
+
+        if press.consumed {
+            return false;
+        }
+
+        if held {
+            press.consumed = true;
+        } else {
+            self.presses.remove(&event);
+        }
+        true
+    }
+
+    /// Checks if an event has been pressed and is still held without consuming it.
+    ///
+    /// # Arguments
+    /// * `event` - The event to check for.
+    ///
+    /// # Returns
+    /// * `bool` - True if the event is pressed and held.
+    pub fn check(&self, event: PlayerEvents) -> bool {
+        self.presses.contains_key(&event) && self.held.contains(&event)
+    }
+
+    /// Checks if the button of an event is held down, no matter how long ago it was pressed or
+    /// whether the press has been fetched.
+    ///
+    /// # Arguments
+    /// * `event` - The event to check for.
+    ///
+    /// # Returns
+    /// * `bool` - True if the button is held down.
+    pub fn is_held(&self, event: PlayerEvents) -> bool {
+        self.held.contains(&event)
+    }
+
+    /// Fetches a buffered release of an event, removing it so each release is only handled once.
+    ///
+    /// # Arguments
+    /// * `event` - The event to fetch the release of.
+    ///
+    /// # Returns
+    /// * `bool` - True if the button was released within the buffer window.
+    pub fn fetch_release(&mut self, event: PlayerEvents) -> bool {
+        self.releases.remove(&event).is_some()
+    }
+
+    /// Removes the presses and releases whose buffer window has run out.
+    ///
+    /// # Arguments
+    /// * `now` - The current time.
+    /// * `window` - Gets the buffer window of an event, None if it lasts until the button is
+    ///   released.
+    pub fn expire(&mut self, now: Instant, window: impl Fn(PlayerEvents) -> Option<Duration>) {
+        let in_window = |event: &PlayerEvents, time: &Instant| {
+            window(*event).is_none_or(|window| now.duration_since(*time) < window)
+        };
+
+        self.presses
+            .retain(|event, press| in_window(event, &press.time));
+        self.releases.retain(|event, time| in_window(event, time));
+    }
+
+    /// Forgets every held and buffered button.
+    pub fn clear(&mut self) {
+        self.presses.clear();
+        self.held.clear();
+        self.releases.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The buffer window used by the tests
+    const WINDOW: Option<Duration> = Some(Duration::from_millis(100));
+
+    #[test]
+    fn press_is_fetched_once() {
+        let mut buffer = InputBuffer::default();
+        buffer.press(PlayerEvents::Jump, Instant::now());
+
+        assert!(buffer.fetch(PlayerEvents::Jump));
+        assert!(!buffer.fetch(PlayerEvents::Jump));
+    }
+
+    #[test]
+    fn fetched_press_is_checked_while_held() {
+        let mut buffer = InputBuffer::default();
+        let now = Instant::now();
+        buffer.press(PlayerEvents::Jump, now);
+
+        assert!(buffer.fetch(PlayerEvents::Jump));
+        assert!(buffer.check(PlayerEvents::Jump));
+
+        buffer.release(PlayerEvents::Jump, now, WINDOW);
+        assert!(!buffer.check(PlayerEvents::Jump));
+        assert!(!buffer.fetch(PlayerEvents::Jump));
+    }
+
+    #[test]
+    fn released_press_is_fetched_within_window() {
+        let mut buffer = InputBuffer::default();
+        let now = Instant::now();
+        buffer.press(PlayerEvents::Jump, now);
+        buffer.release(PlayerEvents::Jump, now, WINDOW);
+
+        buffer.expire(now + Duration::from_millis(50), |_| WINDOW);
+        assert!(!buffer.check(PlayerEvents::Jump));
+        assert!(buffer.fetch(PlayerEvents::Jump));
+        assert!(!buffer.fetch(PlayerEvents::Jump));
+    }
+
+    #[test]
+    fn press_expires_after_window() {
+        let mut buffer = InputBuffer::default();
+        let now = Instant::now();
+        buffer.press(PlayerEvents::Jump, now);
+
+        buffer.expire(now + Duration::from_millis(150), |_| WINDOW);
+        assert!(buffer.is_held(PlayerEvents::Jump));
+        assert!(!buffer.check(PlayerEvents::Jump));
+        assert!(!buffer.fetch(PlayerEvents::Jump));
+    }
+
+    #[test]
+    fn press_without_window_lasts_until_release() {
+        let mut buffer = InputBuffer::default();
+        let now = Instant::now();
+        buffer.press(PlayerEvents::Block, now);
+
+        buffer.expire(now + Duration::from_secs(10), |_| None);
+        assert!(buffer.check(PlayerEvents::Block));
+
+        buffer.release(PlayerEvents::Block, now, None);
+        assert!(!buffer.check(PlayerEvents::Block));
+        assert!(!buffer.fetch(PlayerEvents::Block));
+    }
+
+    #[test]
+    fn release_is_fetched_once_within_window() {
+        let mut buffer = InputBuffer::default();
+        let now = Instant::now();
+        buffer.press(PlayerEvents::Jump, now);
+        buffer.release(PlayerEvents::Jump, now, WINDOW);
+
+        assert!(buffer.fetch_release(PlayerEvents::Jump));
+        assert!(!buffer.fetch_release(PlayerEvents::Jump));
+
+        buffer.press(PlayerEvents::Jump, now);
+        buffer.release(PlayerEvents::Jump, now, WINDOW);
+        buffer.expire(now + Duration::from_millis(150), |_| WINDOW);
+        assert!(!buffer.fetch_release(PlayerEvents::Jump));
+    }
+
+    #[test]
+    fn pressing_again_clears_the_release() {
+        let mut buffer = InputBuffer::default();
+        let now = Instant::now();
+        buffer.press(PlayerEvents::Jump, now);
+        buffer.release(PlayerEvents::Jump, now, WINDOW);
+        buffer.press(PlayerEvents::Jump, now);
+
+        assert!(!buffer.fetch_release(PlayerEvents::Jump));
+        assert!(buffer.is_held(PlayerEvents::Jump));
+    }
+}
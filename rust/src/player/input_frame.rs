@@ -0,0 +1,125 @@
+//! input_frame.rs
+//!
+//! This file defines the `InputFrame` struct, a snapshot of everything a player did with their
+//! controls during one physics frame. Frames are what recorded replays are made of and what
+//! scripted and networked players are driven with.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use godot::prelude::*;
+
+use super::enums::input_action::InputAction;
+use super::enums::metal_type::MetalType;
+
+/// The input of a player during a single physics frame.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InputFrame {
+    /// How far the player is moving left or right from -1 to 1.
+    pub horizontal: f32,
+    /// How far the player is holding up or down from -1 to 1, with up being negative.
+    pub vertical: f32,
+    /// The direction the player is aiming in.
+    pub aim: Vector2,
+    /// How hard the player is burning steel from 0 to 1.
+    pub steel_strength: f32,
+    /// How hard the player is burning iron from 0 to 1.
+    pub iron_strength: f32,
+    /// The buttons pressed or released during the frame in the order it happened, given as the
+    /// button's action and whether it was pressed.
+    pub buttons: Vec<(InputAction, bool)>,
+}
+
+impl InputFrame {
+    /// Gets how hard a metal is burned during the frame.
+    ///
+    /// # Arguments
+    /// * `metal` - The metal being burned.
+    ///
+    /// # Returns
+    /// * `f32` - How hard the metal is burned from 0 to 1. Only steel and iron have a strength.
+    pub fn burn_strength(&self, metal: MetalType) -> f32 {
+        match metal {
+            MetalType::Steel => self.steel_strength,
+            MetalType::Iron => self.iron_strength,
+            _ => 0.0,
+        }
+    }
+
+    /// Converts the frame into a dictionary so it can be sent to another peer or saved.
+    /// Buttons pressed and released in the same frame are sent as both a press and a release.
+    ///
+    /// # Returns
+    /// * `Dictionary` - The frame with the keys horizontal, vertical, aim, steel, iron, pressed
+    ///   and released.
+    pub fn to_dictionary(&self) -> Dictionary {
+        let mut pressed = Array::<GString>::new();
+        let mut released = Array::<GString>::new();
+        for (button, is_pressed) in &self.buttons {
+            let name = GString::from(button.as_str());
+            if *is_pressed {
+                pressed.push(&name);
+            } else {
+                released.push(&name);
+            }
+        }
+
+        let mut dictionary = Dictionary::new();
+        dictionary.set("horizontal", self.horizontal);
+        dictionary.set("vertical", self.vertical);
+        dictionary.set("aim", self.aim);
+        dictionary.set("steel", self.steel_strength);
+        dictionary.set("iron", self.iron_strength);
+        dictionary.set("pressed", pressed);
+        dictionary.set("released", released);
+        dictionary
+    }
+
+    /// Reads a frame from a dictionary made by `to_dictionary`.
+    /// Missing keys are treated as no input so partial frames can be written by hand, and buttons
+    /// that are not actions the input manager handles are skipped.
+    ///
+    /// # Arguments
+    /// * `dictionary` - The dictionary to read the frame from.
+    ///
+    /// # Returns
+    /// * `InputFrame` - The frame described by the dictionary.
+    pub fn from_dictionary(dictionary: &Dictionary) -> InputFrame {
+        let float = |key: &str| {
+            dictionary
+                .get(key)
+                .and_then(|value| value.try_to::<f32>().ok())
+                .unwrap_or_default()
+        };
+        let buttons = |key: &str| {
+            dictionary
+                .get(key)
+                .and_then(|value| value.try_to::<Array<GString>>().ok())
+                .map(|array| {
+                    array
+                        .iter_shared()
+                        .filter_map(|name| InputAction::from_string(&name.to_string()))
+                        .collect()
+                })
+                .unwrap_or_else(Vec::new)
+        };
+
+        let pressed: Vec<InputAction> = buttons("pressed");
+        let released: Vec<InputAction> = buttons("released");
+
+        InputFrame {
+            horizontal: float("horizontal"),
+            vertical: float("vertical"),
+            aim: dictionary
+                .get("aim")
+                .and_then(|value| value.try_to::<Vector2>().ok())
+                .unwrap_or_default(),
+            steel_strength: float("steel"),
+            iron_strength: float("iron"),
+            buttons: pressed
+                .into_iter()
+                .map(|button| (button, true))
+                .chain(released.into_iter().map(|button| (button, false)))
+                .collect(),
+        }
+    }
+}
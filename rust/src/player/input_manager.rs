@@ -7,16 +7,31 @@
 //!
 //! Author: Charles Barth
 //! Version: Spring 2025
-use godot::classes::InputMap;
-use godot::global::{JoyAxis, JoyButton};
+use godot::classes::Engine;
+use godot::global::JoyButton;
 use godot::{classes::InputEvent, prelude::*};
 use std::collections::{HashMap, HashSet};
+use std::time::Instant;
 
+use super::enums::gesture::GestureOutput;
+use super::enums::input_action::InputAction;
+use super::enums::input_binding::InputBinding;
 use super::enums::metal_type::{BurnType, ButtonState, MetalType};
 use super::enums::player_events::PlayerEvents;
+use super::gesture_recognizer::{GestureBinding, GestureRecognizer, GestureSignal};
+use super::input_buffer::InputBuffer;
+use super::input_frame::InputFrame;
+use super::input_sources::hardware_input::HardwareInput;
+use super::input_sources::network_input::NetworkInput;
+use super::input_sources::replay_input::ReplayInput;
+use super::input_sources::scripted_input::ScriptedInput;
+use super::keyboard_profile::KEYBOARD_DEVICE_ID;
 use super::metal_manager::MetalManager;
 use super::player::Player;
+use super::player_bindings::PlayerBindings;
+use super::traits::input_source::InputSource;
 use super::traits::metal::Metal;
+use crate::settings::Settings;
 
 /// The input manager is responsible for handling all input events for a given
 /// player and device.
@@ -29,19 +44,52 @@ use super::traits::metal::Metal;
 pub struct InputManager {
     /// The base node of the InputManager.
     base: Base<Node2D>,
-    /// The player events that have been triggered.
-    /// Events will be removed after a certain number of frames or when the
-    /// button is released.
-    player_events: HashMap<PlayerEvents, i8>,
+    /// The player events that have been pressed, are held and have been
+    /// released. Presses and releases are buffered until their buffer window
+    /// runs out.
+    buffer: InputBuffer,
     /// The metal events that have been triggered.
     /// This will persist until the button is released.
     metal_events: HashSet<(MetalType, BurnType, ButtonState)>,
     /// A hashmap to keep track of whether a button has been released.
     /// This prevents an event from being triggered multiple times while a
     /// button is held down.
-    button_released: HashMap<String, bool>,
-    /// The device id that the input manager is listening for.
+    button_released: HashMap<InputAction, bool>,
+    /// The device the player joined with, read when the player goes back to
+    /// hardware input.
     device_id: i32,
+    /// Where the player's input comes from. Every stick, trigger and button
+    /// is read through the source.
+    source: Box<dyn InputSource>,
+    /// The frames recorded so far while recording is on.
+    recording: Option<Vec<InputFrame>>,
+    /// The last recording that was finished, kept so it can be replayed.
+    last_recording: Vec<InputFrame>,
+    /// The buttons pressed or released since the last physics frame, in the
+    /// order it happened. These are saved into the recording.
+    frame_buttons: Vec<(InputAction, bool)>,
+    /// The player event that was most recently fetched and the physics frame it
+    /// was fetched on, used to record what triggered a player's state transition.
+    last_fetched_event: Option<(PlayerEvents, u64)>,
+    /// The settings for the game, used to look up the buffer window of each event.
+    settings: Gd<Settings>,
+    /// Recognizes taps, holds, double taps and chords on buttons with gesture
+    /// bindings. Presses of those buttons go through the recognizer instead of
+    /// being turned into events directly.
+    gestures: GestureRecognizer,
+    /// The actions this player has rebound. These take the place of the
+    /// global input map's events for the player only.
+    bindings: PlayerBindings,
+    /// The name the player's bindings are saved under.
+    binding_profile: String,
+    /// The action each button bound for this player triggers. This is built
+    /// from the input map and the player's bindings whenever either changes
+    /// so input events do not have to be checked against every action.
+    action_table: HashMap<InputBinding, InputAction>,
+    /// The bound buttons that are held down. Triggers send an event every
+    /// time they move, so only changes between pressed and released are
+    /// handled.
+    pressed_bindings: HashSet<InputBinding>,
 }
 
 #[godot_api]
@@ -54,12 +102,29 @@ impl INode2D for InputManager {
     /// # Returns
     /// * `InputManager` - A new instance of the InputManager class.
     fn init(base: Base<Node2D>) -> Self {
+        let settings = Engine::singleton()
+            .get_singleton("Settings")
+            .expect("settings singleton missing")
+            .try_cast::<Settings>()
+            .expect("settings is not a Settings");
+
         Self {
             base,
-            player_events: HashMap::new(),
+            buffer: InputBuffer::default(),
             metal_events: HashSet::new(),
             button_released: HashMap::new(),
             device_id: -1,
+            source: Box::new(HardwareInput::new(-1)),
+            recording: None,
+            last_recording: Vec::new(),
+            frame_buttons: Vec::new(),
+            last_fetched_event: None,
+            settings,
+            gestures: GestureRecognizer::default(),
+            bindings: PlayerBindings::default(),
+            binding_profile: String::new(),
+            action_table: HashMap::new(),
+            pressed_bindings: HashSet::new(),
         }
     }
 
@@ -71,63 +136,90 @@ impl INode2D for InputManager {
     /// # Arguments
     /// * `event` - The input event that was detected.
     fn input(&mut self, event: Gd<InputEvent>) {
-        if self.device_id == -1 || event.get_device() != self.device_id || event.is_echo() {
+        if !self.source.accept_event(&event) {
             return;
         }
 
-        let button_name = InputManager::event_to_input_name(event.clone());
-
-        if button_name == "" {
+        let Some(binding) = InputBinding::from_event(&event) else {
             return;
-        }
+        };
+        let Some(&action) = self.action_table.get(&binding) else {
+            return;
+        };
 
-        if !self.button_released.contains_key(&button_name) {
-            self.button_released.insert(button_name.clone(), true);
+        let pressed = event.is_pressed();
+        if pressed == self.pressed_bindings.contains(&binding) {
+            return;
         }
 
-        if let Some(player_event) = PlayerEvents::from_string(&button_name) {
-            self.process_player_events(player_event, event, button_name);
-        } else if let Some(metal_type) = MetalType::from_string(&button_name) {
-            self.process_metal_events(metal_type, event, button_name);
+        if pressed {
+            self.pressed_bindings.insert(binding);
+        } else {
+            self.pressed_bindings.remove(&binding);
         }
+        self.process_button(action, pressed);
     }
 
     /// This is a built in method for Godot that is called every physics frame.
-    /// This is where the player events are updated and expired after a certain
-    /// number of frames.
+    /// This is where buffered presses and releases are expired once their
+    /// buffer window runs out. The windows are measured in time rather than
+    /// frames so they last equally long at any physics tick rate.
+    /// Held gestures and taps that are no longer waiting for a second tap are
+    /// also sent here. Sources that are not driven by Godot input events hand
+    /// over their buttons for the frame first, and the frame is added to the
+    /// recording if one is being made.
     ///
     /// # Arguments
     /// * `delta` - The time since the last frame.
     fn physics_process(&mut self, _delta: f64) {
-        for (event, timer) in self.player_events.iter_mut() {
-            if event.timeout() > -1 {
-                *timer += 1;
+        for (action, pressed) in self.source.advance() {
+            self.process_button(action, pressed);
+        }
+
+        let buttons = std::mem::take(&mut self.frame_buttons);
+        if self.recording.is_some() {
+            let frame = InputFrame {
+                horizontal: self.get_horizontal_movement(),
+                vertical: self.get_vertical_movement(),
+                aim: self.get_aim_direction(),
+                steel_strength: self.get_burn_strength(MetalType::Steel),
+                iron_strength: self.get_burn_strength(MetalType::Iron),
+                buttons,
+            };
+            if let Some(recording) = self.recording.as_mut() {
+                recording.push(frame);
             }
         }
 
-        // Expire events after a certain number of frames (e.g., 60 frames)
-        self.player_events
-            .retain(|event, timer| event.timeout() == -1 || *timer < event.timeout());
+        let signals = self.gestures.update(Instant::now());
+        self.apply_gesture_signals(signals);
+
+        let settings = self.settings.bind();
+        self.buffer.expire(Instant::now(), |event| settings.get_input_buffer(event));
     }
 }
 
 #[godot_api]
 impl InputManager {
-    /// Fetching the events checks if the event is in the hashmap and if it is
-    /// it removes it and returns true otherwise it returns false.
+    /// Fetching the events checks if there is a buffered press of the event
+    /// that has not been fetched yet and if there is it consumes it and
+    /// returns true otherwise it returns false. The press is still buffered
+    /// while the button is held so checking for the event keeps working.
     ///
     /// Arguments:
     /// * `event` - The event to fetch
     ///
     /// Returns:
-    /// * `bool` - True if the event was in the hashmap and removed,
+    /// * `bool` - True if an unfetched press of the event was buffered,
     ///            false otherwise
     pub fn fetch_player_event(&mut self, event: PlayerEvents) -> bool {
-        if let Some(_) = self.player_events.remove(&event) {
-            true
-        } else {
-            false
+        if !self.buffer.fetch(event) {
+            return false;
         }
+
+        let frame = Engine::singleton().get_physics_frames();
+        self.last_fetched_event = Some((event, frame));
+        true
     }
 
     // #[func]
@@ -139,16 +231,104 @@ impl InputManager {
     //     }
     // }
 
-    /// Checks if the event is in the hashmap but does not remove it.
+    /// Takes the player event that was most recently fetched so it is only reported once.
+    /// Events fetched on an earlier physics frame did not trigger anything the
+    /// player is doing now, such as a press fetched for a combo, so they are dropped.
+    ///
+    /// # Returns
+    /// * `Option<PlayerEvents>` - The event, if one was fetched this physics frame.
+    pub fn take_last_fetched_event(&mut self) -> Option<PlayerEvents> {
+        let frame = Engine::singleton().get_physics_frames();
+        self.last_fetched_event
+            .take()
+            .filter(|(_, fetched_on)| *fetched_on == frame)
+            .map(|(event, _)| event)
+    }
+
+    /// Checks if the event has been pressed and is still held but does not
+    /// consume it. Events with a buffer window stop being reported once the
+    /// window runs out even if the button is still held.
     ///
     /// # Arguments
     /// * `event` - The event to check for
     ///
     /// # Returns
-    /// * `bool` - True if the event is in the hashmap, false otherwise
+    /// * `bool` - True if the event is pressed and held, false otherwise
     #[func]
     pub fn check_for_player_event(&self, event: PlayerEvents) -> bool {
-        self.player_events.contains_key(&event)
+        self.buffer.check(event)
+    }
+
+    /// Checks if the button of an event is held down, no matter how long ago
+    /// it was pressed or whether the press has been fetched.
+    ///
+    /// # Arguments
+    /// * `event` - The event to check for
+    ///
+    /// # Returns
+    /// * `bool` - True if the button is held down
+    #[func]
+    pub fn is_player_event_held(&self, event: PlayerEvents) -> bool {
+        self.buffer.is_held(event)
+    }
+
+    /// Fetches a buffered release of an event, removing it so each release is
+    /// only handled once.
+    ///
+    /// # Arguments
+    /// * `event` - The event to fetch the release of
+    ///
+    /// # Returns
+    /// * `bool` - True if the button was released within the buffer window
+    pub fn fetch_player_event_release(&mut self, event: PlayerEvents) -> bool {
+        self.buffer.fetch_release(event)
+    }
+
+    /// Feeds a synthetic press or release of a player event into the input
+    /// manager as if it came from the player's device. This is used to drive
+    /// players without a device, such as in tests.
+    ///
+    /// # Arguments
+    /// * `event` - The event to press or release
+    /// * `pressed` - True to press the event's button, false to release it
+    #[func]
+    pub fn push_player_event(&mut self, event: PlayerEvents, pressed: bool) {
+        self.button_released
+            .entry(InputAction::Player(event))
+            .or_insert(true);
+        self.process_player_events(event, pressed);
+    }
+
+    /// Handles a press or release of a button, whichever source it came
+    /// from. Buttons with gesture bindings go through the gesture recognizer
+    /// and the rest are turned into player events or metal events.
+    ///
+    /// Arguments:
+    /// * `action` - The action of the button
+    /// * `pressed` - True if the button was pressed, false if it was released
+    fn process_button(&mut self, action: InputAction, pressed: bool) {
+        if self.recording.is_some() {
+            self.frame_buttons.push((action, pressed));
+        }
+
+        self.button_released.entry(action).or_insert(true);
+
+        if self.gestures.handles(action.as_str()) {
+            let signals = if pressed {
+                self.gestures.press(action.as_str(), Instant::now())
+            } else {
+                self.gestures.release(action.as_str(), Instant::now())
+            };
+            self.apply_gesture_signals(signals);
+            return;
+        }
+
+        match action {
+            InputAction::Player(player_event) => {
+                self.process_player_events(player_event, pressed);
+            }
+            InputAction::Metal(metal_type) => self.process_metal_events(metal_type, pressed),
+        }
     }
 
     #[func]
@@ -156,56 +336,104 @@ impl InputManager {
         PlayerEvents::from_string(&event).expect("Couldn't parse into a player event.")
     }
 
-    /// Takes an InputEvent and returns the name of the input event.
+    /// Takes an InputEvent and returns the action it triggers for this player.
     ///  
     /// # Arguments
-    /// * `event` (`Gd<InputEvent>`) - the input event to convert to string
-    ///                                representation
+    /// * `event` (`&Gd<InputEvent>`) - the input event to look up
     /// # Returns
-    /// * `String`` - the string representation of the event
-    pub fn event_to_input_name(event: Gd<InputEvent>) -> String {
-        let mut input_map = InputMap::singleton();
-        let inputs = input_map.get_actions();
-
-        let length = inputs.len();
-        for i in (0..length).rev() {
-            let input = inputs.get(i).unwrap();
-            let input_str = input.to_string();
-
-            // Skip inputs that start with "ui_"
-            if input_str.starts_with("ui_") {
-                continue;
-            }
+    /// * `Option<InputAction>` - the action, or None if the event is not
+    ///                           bound to an action for this player
+    pub fn event_to_action(&self, event: &Gd<InputEvent>) -> Option<InputAction> {
+        InputBinding::from_event(event)
+            .and_then(|binding| self.action_table.get(&binding).copied())
+    }
+
+    /// Rebuilds the table used to find the action of each button. This has to
+    /// be called after the input map is changed for the change to reach the
+    /// player.
+    #[func]
+    pub fn rebuild_action_table(&mut self) {
+        self.action_table = self.bindings.lookup_table(self.device_id);
+    }
 
-            if input_map.event_is_action(&event, &input) {
-                return input_str;
+    /// Adds a gesture binding so a button can trigger different events
+    /// depending on how it is used.
+    ///
+    /// Arguments:
+    /// * `binding` - The binding to add
+    pub fn add_gesture_binding(&mut self, binding: GestureBinding) {
+        self.gestures.add_binding(binding);
+    }
+
+    /// Turns tap and hold gestures on the metal buttons on or off. With them
+    /// on, tapping a metal button toggles low burning it and holding it burns
+    /// the metal, which frees up the low burn button.
+    ///
+    /// Arguments:
+    /// * `enabled` - True to use gestures on the metal buttons
+    #[func]
+    pub fn set_metal_gestures(&mut self, enabled: bool) {
+        for metal in MetalType::iter() {
+            self.gestures.remove_bindings(metal.as_str());
+            if enabled {
+                for binding in GestureBinding::metal_tap_hold(metal) {
+                    self.gestures.add_binding(binding);
+                }
             }
         }
+    }
+
+    /// Applies the events started and ended by gestures as if their own
+    /// buttons had been pressed and released.
+    ///
+    /// Arguments:
+    /// * `signals` - The events started and ended by gestures
+    fn apply_gesture_signals(&mut self, signals: Vec<GestureSignal>) {
+        for signal in signals {
+            let (output, started) = match signal {
+                GestureSignal::Start(output) => (output, true),
+                GestureSignal::End(output) => (output, false),
+            };
 
-        "".to_string()
+            match output {
+                GestureOutput::Player(player_event) => {
+                    self.push_player_event(player_event, started);
+                }
+                // Low burns toggle on each gesture the same way the low burn
+                // modifier does, while burns last for as long as the gesture
+                GestureOutput::Metal(metal_type, BurnType::LowBurn) => {
+                    let metal_event = (metal_type, BurnType::LowBurn, ButtonState::Pressed);
+                    if started && !self.metal_events.remove(&metal_event) {
+                        self.metal_events.insert(metal_event);
+                    }
+                }
+                GestureOutput::Metal(metal_type, BurnType::Burn) => {
+                    let metal_event = (metal_type, BurnType::Burn, ButtonState::Pressed);
+                    if started {
+                        self.metal_events.insert(metal_event);
+                    } else {
+                        self.metal_events.remove(&metal_event);
+                    }
+                }
+            }
+        }
     }
 
     /// This function takes a MetalEvent and determines if it should be stored,
     /// removed, or toggled.
     ///
     /// Arguments:
-    /// * `metal_event` - The MetalEvent to process
-    /// * `event` - The input event that was detected
-    /// * `button_name` - The name of the button that was pressed
-    fn process_metal_events(
-        &mut self,
-        metal_type: MetalType,
-        event: Gd<InputEvent>,
-        button_name: String,
-    ) {
-        let burn_type = if self.player_events.contains_key(&PlayerEvents::LowBurn) {
+    /// * `metal_type` - The metal whose button was pressed or released
+    /// * `pressed` - True if the button was pressed, false if it was released
+    fn process_metal_events(&mut self, metal_type: MetalType, pressed: bool) {
+        let burn_type = if self.buffer.is_held(PlayerEvents::LowBurn) {
             BurnType::LowBurn
         } else {
             BurnType::Burn
         };
 
         // If the button is pressed
-        if event.is_action_pressed(button_name.as_str()) {
+        if pressed {
             // If the player is holding down the low burn button then this is a
             // low burn event
             if burn_type == BurnType::LowBurn {
@@ -231,7 +459,7 @@ impl InputManager {
             }
 
         // If the button is released
-        } else if burn_type != BurnType::LowBurn && event.is_action_released(button_name.as_str()) {
+        } else if burn_type != BurnType::LowBurn {
             self.metal_events
                 .remove(&(metal_type, burn_type, ButtonState::Pressed));
         }
@@ -257,38 +485,319 @@ impl InputManager {
         }
     }
 
-    /// This function takes a PlayerEvent and determines if it should be stored
-    /// or removed.
+    /// This function takes a press or release of a PlayerEvent and buffers it.
     /// It also keeps track of whether a button has been released to prevent an
     /// event from being triggered multiple times while a button is held down.
+    /// Presses of events with a buffer window stay buffered after the button is
+    /// released so an early press is not lost, while events without one are
+    /// removed on release.
     ///
     /// Arguments:
     /// * `player_event` - The PlayerEvent to process
-    /// * `event` - The input event that was detected
-    /// * `button_name` - The name of the button that was pressed
-    fn process_player_events(
-        &mut self,
-        player_event: PlayerEvents,
-        event: Gd<InputEvent>,
-        button_name: String,
-    ) {
-        if event.is_pressed()
-            && !self.player_events.contains_key(&player_event)
-            && *self.button_released.get(&button_name).unwrap()
+    /// * `pressed` - True if the button was pressed, false if it was released
+    fn process_player_events(&mut self, player_event: PlayerEvents, pressed: bool) {
+        let button = InputAction::Player(player_event);
+        if pressed
+            && !self.buffer.is_held(player_event)
+            && *self.button_released.get(&button).unwrap()
         {
-            self.button_released.insert(button_name, false);
-            self.player_events.insert(player_event, 0);
-        } else if event.is_released() {
-            self.button_released.insert(button_name, true);
-            self.player_events.remove(&player_event);
+            self.button_released.insert(button, false);
+            self.buffer.press(player_event, Instant::now());
+        } else if !pressed {
+            self.button_released.insert(button, true);
+            let window = self.settings.bind().get_input_buffer(player_event);
+            self.buffer.release(player_event, Instant::now(), window);
         }
     }
 
+    /// Gets how far the player is moving left or right.
+    ///
+    /// Returns:
+    /// * `f32` - The horizontal movement from -1 to 1
+    pub fn get_horizontal_movement(&self) -> f32 {
+        self.source.horizontal_movement()
+    }
+
+    /// Gets how far the player is holding up or down.
+    /// Up is negative to match Godot's y axis.
+    ///
+    /// Returns:
+    /// * `f32` - The vertical movement from -1 to 1
+    pub fn get_vertical_movement(&self) -> f32 {
+        self.source.vertical_movement()
+    }
+
+    /// Gets the direction the player is aiming in.
+    /// Its length is how far the player is aiming past their deadzone, or zero
+    /// inside it, except for mouse aim which always points from the player to
+    /// the cursor.
+    ///
+    /// Returns:
+    /// * `Vector2` - The direction the player is aiming in
+    pub fn get_aim_direction(&self) -> Vector2 {
+        let base = self.base();
+        self.source
+            .aim_direction(base.get_global_position(), base.get_global_mouse_position())
+    }
+
+    /// Gets how hard the player is burning a metal that pushes or pulls.
+    ///
+    /// Arguments:
+    /// * `metal_type` - The metal being burned
+    ///
+    /// Returns:
+    /// * `f32` - How hard the metal is burned from 0 to 1
+    pub fn get_burn_strength(&self, metal_type: MetalType) -> f32 {
+        self.source.burn_strength(metal_type)
+    }
+
+    /// Checks if the input manager is listening to the keyboard and mouse.
+    ///
+    /// Returns:
+    /// * `bool` - True if the player uses the keyboard and mouse
+    pub fn is_keyboard(&self) -> bool {
+        self.source.is_keyboard()
+    }
+
     /// Sets the device id that the input manager is listening for.
+    /// This switches the player back to reading input from hardware.
     ///
     /// Arguments:
     /// * `device_id` - The device id to set
     pub fn set_device_id(&mut self, device_id: i32) {
         self.device_id = device_id;
+        self.set_input_source(Box::new(HardwareInput::new(device_id)));
+
+        let profile = if device_id == KEYBOARD_DEVICE_ID {
+            "keyboard".to_string()
+        } else {
+            format!("device{}", device_id)
+        };
+        self.set_binding_profile(profile);
+    }
+
+    /// Switches to another set of saved bindings, such as when a player
+    /// picks their profile.
+    ///
+    /// Arguments:
+    /// * `profile` - The name the bindings are saved under
+    #[func]
+    pub fn set_binding_profile(&mut self, profile: String) {
+        self.bindings = PlayerBindings::load(&profile);
+        self.binding_profile = profile;
+        self.rebuild_action_table();
+        self.release_all_buttons();
+    }
+
+    /// Gets the name the player's bindings are saved under.
+    ///
+    /// Returns:
+    /// * `String` - The name of the binding profile
+    #[func]
+    pub fn get_binding_profile(&self) -> String {
+        self.binding_profile.clone()
+    }
+
+    /// Binds an action to a new button for this player only, taking the
+    /// button off any other action it triggered. The binding is saved to the
+    /// player's profile.
+    ///
+    /// Arguments:
+    /// * `action` - The name of the action to rebind
+    /// * `event` - A press of the button to bind the action to
+    ///
+    /// Returns:
+    /// * `bool` - True if the action was rebound, false if the button can
+    ///            not be bound, such as a stick
+    #[func]
+    pub fn rebind_action(&mut self, action: String, event: Gd<InputEvent>) -> bool {
+        let Some(binding) = InputBinding::from_event(&event) else {
+            return false;
+        };
+
+        self.bindings.rebind(&action, binding, self.device_id);
+        self.save_bindings();
+        true
+    }
+
+    /// Moves a button from whichever action it triggers to another action for
+    /// this player only, keeping the other buttons of both actions. This is
+    /// how the metal wheel swaps which metal a button burns.
+    ///
+    /// Arguments:
+    /// * `action` - The name of the action to give the button to
+    /// * `event` - A press of the button to move
+    ///
+    /// Returns:
+    /// * `bool` - True if the button was moved, false if it can not be bound
+    #[func]
+    pub fn swap_action_binding(&mut self, action: String, event: Gd<InputEvent>) -> bool {
+        let Some(binding) = InputBinding::from_event(&event) else {
+            return false;
+        };
+
+        self.bindings.swap(&action, binding, self.device_id);
+        self.save_bindings();
+        true
+    }
+
+    /// Removes every button of an action for this player.
+    ///
+    /// Arguments:
+    /// * `action` - The name of the action to unbind
+    #[func]
+    pub fn unbind_action(&mut self, action: String) {
+        self.bindings.unbind(&action);
+        self.save_bindings();
+    }
+
+    /// Puts an action back to the buttons in the global input map for this
+    /// player.
+    ///
+    /// Arguments:
+    /// * `action` - The name of the action to reset
+    #[func]
+    pub fn reset_action_binding(&mut self, action: String) {
+        self.bindings.reset(&action);
+        self.save_bindings();
+    }
+
+    /// Puts every action back to the buttons in the global input map for
+    /// this player.
+    #[func]
+    pub fn reset_all_bindings(&mut self) {
+        self.bindings.reset_all();
+        self.save_bindings();
+    }
+
+    /// Gets the buttons that trigger an action for this player, such as to
+    /// show them in the controls menu.
+    ///
+    /// Arguments:
+    /// * `action` - The name of the action
+    ///
+    /// Returns:
+    /// * `Array<Gd<InputEvent>>` - A press of each button bound to the action
+    #[func]
+    pub fn get_action_events(&self, action: String) -> Array<Gd<InputEvent>> {
+        self.bindings
+            .get(&action, self.device_id)
+            .iter()
+            .map(|binding| binding.to_event(self.device_id))
+            .collect()
+    }
+
+    /// Gets the action a button triggers for this player.
+    ///
+    /// Arguments:
+    /// * `event` - A press of the button
+    ///
+    /// Returns:
+    /// * `String` - The name of the action, or an empty string if the button
+    ///              is not bound for this player
+    #[func]
+    pub fn get_event_action(&self, event: Gd<InputEvent>) -> String {
+        self.event_to_action(&event)
+            .map(|action| action.as_str().to_string())
+            .unwrap_or_default()
+    }
+
+    /// Saves the player's bindings and lets go of every held button, since a
+    /// held button may no longer be bound to the action it pressed.
+    fn save_bindings(&mut self) {
+        self.bindings.save(&self.binding_profile);
+        self.rebuild_action_table();
+        self.release_all_buttons();
+    }
+
+    /// Forgets every held and buffered button.
+    fn release_all_buttons(&mut self) {
+        self.buffer.clear();
+        self.metal_events.clear();
+        self.button_released.clear();
+        self.pressed_bindings.clear();
+        self.frame_buttons.clear();
+        self.gestures.reset();
+    }
+
+    /// Replaces where the player's input comes from.
+    /// Anything held or buffered from the old source is dropped so no button
+    /// is left stuck down.
+    ///
+    /// Arguments:
+    /// * `source` - The new source of input
+    pub fn set_input_source(&mut self, source: Box<dyn InputSource>) {
+        self.source = source;
+        self.release_all_buttons();
+    }
+
+    /// Switches the player back to reading the device they joined with.
+    #[func]
+    pub fn use_hardware_input(&mut self) {
+        self.set_device_id(self.device_id);
+    }
+
+    /// Switches the player to input queued by a script with
+    /// `push_input_frame`.
+    #[func]
+    pub fn use_scripted_input(&mut self) {
+        self.set_input_source(Box::new(ScriptedInput::default()));
+    }
+
+    /// Switches the player to input received from a remote peer. Received
+    /// frames are passed in with `push_input_frame`.
+    #[func]
+    pub fn use_network_input(&mut self) {
+        self.set_input_source(Box::new(NetworkInput::default()));
+    }
+
+    /// Switches the player to playing back the last finished recording.
+    #[func]
+    pub fn use_replay_input(&mut self) {
+        let frames = self.last_recording.clone();
+        self.set_input_source(Box::new(ReplayInput::new(frames)));
+    }
+
+    /// Queues a frame of input for scripted and network input. The frame is
+    /// a dictionary in the format of `InputFrame::to_dictionary`.
+    ///
+    /// Arguments:
+    /// * `frame` - The frame to queue
+    #[func]
+    pub fn push_input_frame(&mut self, frame: Dictionary) {
+        self.push_frame(InputFrame::from_dictionary(&frame));
+    }
+
+    /// Queues a frame of input for scripted and network input. Scripted input
+    /// only keeps the newest frame that has not been played yet.
+    ///
+    /// Arguments:
+    /// * `frame` - The frame to queue
+    pub fn push_frame(&mut self, frame: InputFrame) {
+        self.source.push_frame(frame);
+    }
+
+    /// Starts recording the player's input, throwing away any recording that
+    /// was in progress.
+    #[func]
+    pub fn start_recording(&mut self) {
+        self.recording = Some(Vec::new());
+    }
+
+    /// Stops recording the player's input and keeps the recording so it can
+    /// be replayed.
+    ///
+    /// Returns:
+    /// * `Array<Dictionary>` - The recorded frames, oldest first
+    #[func]
+    pub fn stop_recording(&mut self) -> Array<Dictionary> {
+        if let Some(recording) = self.recording.take() {
+            self.last_recording = recording;
+        }
+
+        self.last_recording
+            .iter()
+            .map(|frame| frame.to_dictionary())
+            .collect()
     }
 }
@@ -0,0 +1,264 @@
+//! hardware_input.rs
+//!
+//! This file contains the HardwareInput source which reads a player's controller, or the keyboard
+//! and mouse, through Godot's Input singleton. Every stick and trigger is passed through the
+//! player's analog profile here, so the rest of the game never sees raw controller values.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use godot::classes::{Engine, Input, InputEvent, InputEventJoypadMotion, InputEventMouseMotion};
+use godot::global::JoyAxis;
+use godot::prelude::*;
+
+use crate::player::analog_profile::AnalogProfile;
+use crate::player::enums::aim_source::AimSource;
+use crate::player::enums::input_action::InputAction;
+use crate::player::enums::metal_type::MetalType;
+use crate::player::keyboard_profile::{KeyboardProfile, KEYBOARD_DEVICE_ID};
+use crate::player::traits::input_source::InputSource;
+use crate::settings::Settings;
+
+/// The distance the right stick must be pushed to switch back to joystick aiming
+const AIM_SWITCH_DEADZONE: f32 = 0.2;
+/// How hard the keyboard player burns a metal the moment its mouse button is pressed
+const MOUSE_BURN_MIN: f32 = 0.25;
+/// How long a metal's mouse button must be held to burn it at full strength
+const MOUSE_BURN_RAMP: Duration = Duration::from_millis(750);
+
+/// Reads input from a physical device.
+pub struct HardwareInput {
+    /// The device id the source reads from. A device id of -1 reads nothing.
+    device_id: i32,
+    /// The device the player last aimed with.
+    aim_source: AimSource,
+    /// The settings for the game, used to look up the player's analog profile.
+    settings: Gd<Settings>,
+    /// When the keyboard player pressed the mouse button of each metal they are burning.
+    burn_presses: HashMap<MetalType, Instant>,
+}
+
+impl HardwareInput {
+    /// Creates a source that reads from a device.
+    /// The keyboard player always aims with the mouse.
+    ///
+    /// # Arguments
+    /// * `device_id` - The device to read from.
+    ///
+    /// # Returns
+    /// * `HardwareInput` - The new source.
+    pub fn new(device_id: i32) -> Self {
+        let aim_source = if device_id == KEYBOARD_DEVICE_ID {
+            AimSource::Mouse
+        } else {
+            AimSource::Joystick
+        };
+
+        let settings = Engine::singleton()
+            .get_singleton("Settings")
+            .expect("settings singleton missing")
+            .try_cast::<Settings>()
+            .expect("settings is not a Settings");
+
+        Self {
+            device_id,
+            aim_source,
+            settings,
+            burn_presses: HashMap::new(),
+        }
+    }
+
+    /// Gets the device the player last aimed with.
+    ///
+    /// # Returns
+    /// * `AimSource` - The device the player last aimed with.
+    pub fn get_aim_source(&self) -> AimSource {
+        self.aim_source
+    }
+
+    /// Gets the analog settings of the player.
+    ///
+    /// # Returns
+    /// * `AnalogProfile` - The player's analog profile.
+    fn analog_profile(&self) -> AnalogProfile {
+        self.settings.bind().get_analog_profile(self.device_id)
+    }
+
+    /// Gets the position of the left stick after the player's analog profile
+    /// is applied.
+    ///
+    /// # Returns
+    /// * `Vector2` - The position of the left stick.
+    fn movement_stick(&self) -> Vector2 {
+        let input = Input::singleton();
+        let stick = Vector2::new(
+            input.get_joy_axis(self.device_id, JoyAxis::LEFT_X),
+            input.get_joy_axis(self.device_id, JoyAxis::LEFT_Y),
+        );
+        self.analog_profile().movement.apply_stick(stick)
+    }
+
+    /// Switches the aim source to whichever device the player last aimed with.
+    /// Moving the mouse switches to mouse aiming and tilting the right stick
+    /// past the deadzone switches back to joystick aiming.
+    ///
+    /// # Arguments
+    /// * `event` - The input event that was detected.
+    fn update_aim_source(&mut self, event: &Gd<InputEvent>) {
+        if event.clone().try_cast::<InputEventMouseMotion>().is_ok() {
+            self.aim_source = AimSource::Mouse;
+        } else if let Ok(motion) = event.clone().try_cast::<InputEventJoypadMotion>() {
+            let axis = motion.get_axis();
+            if (axis == JoyAxis::RIGHT_X || axis == JoyAxis::RIGHT_Y)
+                && motion.get_axis_value().abs() > AIM_SWITCH_DEADZONE
+            {
+                self.aim_source = AimSource::Joystick;
+            }
+        }
+    }
+
+    /// Gets how hard the keyboard player burns a metal after holding its mouse
+    /// button for a while. The strength ramps up from the minimum to full
+    /// strength over the ramp time, so a quick click gives a light push.
+    ///
+    /// # Arguments
+    /// * `held` - How long the mouse button has been held.
+    ///
+    /// # Returns
+    /// * `f32` - The burn strength from the minimum to 1.
+    fn mouse_burn_strength(held: Duration) -> f32 {
+        let ramp = (held.as_secs_f32() / MOUSE_BURN_RAMP.as_secs_f32()).min(1.0);
+        MOUSE_BURN_MIN + (1.0 - MOUSE_BURN_MIN) * ramp
+    }
+}
+
+impl InputSource for HardwareInput {
+    fn accept_event(&mut self, event: &Gd<InputEvent>) -> bool {
+        if self.device_id == -1
+            || KeyboardProfile::event_device(event) != self.device_id
+            || event.is_echo()
+        {
+            return false;
+        }
+
+        self.update_aim_source(event);
+        true
+    }
+
+    /// Keeps track of when the keyboard player pressed each metal's mouse
+    /// button so the burn strength can ramp up while it is held.
+    fn advance(&mut self) -> Vec<(InputAction, bool)> {
+        if self.is_keyboard() {
+            let input = Input::singleton();
+            for metal in MetalType::iter() {
+                match KeyboardProfile::burn_button(metal) {
+                    Some(button) if input.is_mouse_button_pressed(button) => {
+                        self.burn_presses.entry(metal).or_insert_with(Instant::now);
+                    }
+                    _ => {
+                        self.burn_presses.remove(&metal);
+                    }
+                }
+            }
+        }
+
+        Vec::new()
+    }
+
+    /// The keyboard player holds the left and right movement keys instead of
+    /// using the stick.
+    fn horizontal_movement(&self) -> f32 {
+        if self.device_id == -1 {
+            return 0.0;
+        }
+
+        if self.is_keyboard() {
+            let (move_left, move_right) = KeyboardProfile::horizontal_actions(self.device_id);
+            return Input::singleton().get_axis(&move_left, &move_right);
+        }
+
+        self.movement_stick().x
+    }
+
+    /// The keyboard player holds the up and down movement keys instead.
+    fn vertical_movement(&self) -> f32 {
+        if self.is_keyboard() {
+            let (move_up, move_down) = KeyboardProfile::vertical_actions();
+            return Input::singleton().get_axis(&move_up, &move_down);
+        }
+
+        self.movement_stick().y
+    }
+
+    /// Joystick aim is zero inside the deadzone and otherwise keeps the
+    /// strength of the stick, while mouse aim always points from the player
+    /// to the cursor, or is zero while the cursor is right on the player.
+    fn aim_direction(&self, origin: Vector2, cursor: Vector2) -> Vector2 {
+        match self.aim_source {
+            AimSource::Joystick => {
+                let input = Input::singleton();
+                let stick = Vector2::new(
+                    input.get_joy_axis(self.device_id, JoyAxis::RIGHT_X),
+                    input.get_joy_axis(self.device_id, JoyAxis::RIGHT_Y),
+                );
+                self.analog_profile().apply_aim(stick)
+            }
+            AimSource::Mouse => (cursor - origin).normalized_or_zero(),
+        }
+    }
+
+    /// Gamepad players burn harder the further they press the metal's
+    /// trigger. The keyboard player burns harder the longer the metal's mouse
+    /// button is held, from a light push on a click up to full strength.
+    fn burn_strength(&self, metal: MetalType) -> f32 {
+        let input = Input::singleton();
+
+        if self.is_keyboard() {
+            return match KeyboardProfile::burn_button(metal) {
+                Some(button) if input.is_mouse_button_pressed(button) => {
+                    let held = self
+                        .burn_presses
+                        .get(&metal)
+                        .map_or(Duration::ZERO, Instant::elapsed);
+                    Self::mouse_burn_strength(held)
+                }
+                _ => 0.0,
+            };
+        }
+
+        let trigger = if metal == MetalType::Steel {
+            JoyAxis::TRIGGER_RIGHT
+        } else {
+            JoyAxis::TRIGGER_LEFT
+        };
+        let pressure = input.get_joy_axis(self.device_id, trigger);
+        self.analog_profile().triggers.apply(pressure)
+    }
+
+    fn is_keyboard(&self) -> bool {
+        self.device_id == KEYBOARD_DEVICE_ID
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mouse_burn_starts_light() {
+        assert_eq!(
+            HardwareInput::mouse_burn_strength(Duration::ZERO),
+            MOUSE_BURN_MIN
+        );
+    }
+
+    #[test]
+    fn mouse_burn_ramps_to_full_strength() {
+        let halfway = HardwareInput::mouse_burn_strength(MOUSE_BURN_RAMP / 2);
+        assert!(halfway > MOUSE_BURN_MIN && halfway < 1.0);
+        assert_eq!(HardwareInput::mouse_burn_strength(MOUSE_BURN_RAMP), 1.0);
+        assert_eq!(HardwareInput::mouse_burn_strength(MOUSE_BURN_RAMP * 4), 1.0);
+    }
+}
@@ -0,0 +1,69 @@
+//! network_input.rs
+//!
+//! This file contains the NetworkInput source which plays input frames received from a remote
+//! peer. Frames arrive unevenly over the network, so the source keeps a small buffer, repeats the
+//! last sticks and triggers it received while it waits and catches up when frames pile up.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use std::collections::VecDeque;
+
+use godot::prelude::*;
+
+use crate::player::enums::input_action::InputAction;
+use crate::player::enums::metal_type::MetalType;
+use crate::player::input_frame::InputFrame;
+use crate::player::traits::input_source::InputSource;
+
+/// The most frames that can wait to be played before the source skips ahead. Skipped frames
+/// still have their buttons played so no press is lost.
+const MAX_BUFFERED_FRAMES: usize = 6;
+
+/// Input received from a remote peer.
+#[derive(Default)]
+pub struct NetworkInput {
+    /// The received frames that have not been played yet.
+    received: VecDeque<InputFrame>,
+    /// The last frame that was played. Its sticks and triggers are repeated until the next frame
+    /// arrives.
+    current: InputFrame,
+}
+
+impl InputSource for NetworkInput {
+    fn advance(&mut self) -> Vec<(InputAction, bool)> {
+        let mut buttons = Vec::new();
+
+        while self.received.len() > MAX_BUFFERED_FRAMES {
+            if let Some(skipped) = self.received.pop_front() {
+                buttons.extend(skipped.buttons);
+            }
+        }
+
+        if let Some(frame) = self.received.pop_front() {
+            buttons.extend(frame.buttons.iter().cloned());
+            self.current = frame;
+        }
+
+        buttons
+    }
+
+    fn push_frame(&mut self, frame: InputFrame) {
+        self.received.push_back(frame);
+    }
+
+    fn horizontal_movement(&self) -> f32 {
+        self.current.horizontal
+    }
+
+    fn vertical_movement(&self) -> f32 {
+        self.current.vertical
+    }
+
+    fn aim_direction(&self, _origin: Vector2, _cursor: Vector2) -> Vector2 {
+        self.current.aim
+    }
+
+    fn burn_strength(&self, metal: MetalType) -> f32 {
+        self.current.burn_strength(metal)
+    }
+}
@@ -0,0 +1,108 @@
+//! replay_input.rs
+//!
+//! This file contains the ReplayInput source which plays back input recorded by an input manager.
+//! A recording played from the same starting point reproduces what the player did, which makes
+//! hard to catch bugs repeatable.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use std::collections::HashSet;
+
+use godot::prelude::*;
+
+use crate::player::enums::input_action::InputAction;
+use crate::player::enums::metal_type::MetalType;
+use crate::player::input_frame::InputFrame;
+use crate::player::traits::input_source::InputSource;
+
+/// Input played back from a recording.
+pub struct ReplayInput {
+    /// The recorded frames.
+    frames: Vec<InputFrame>,
+    /// The index of the frame being played. This is one past the last frame once the replay
+    /// has finished.
+    position: usize,
+    /// The buttons that are held down at the current point of the replay.
+    held_buttons: HashSet<InputAction>,
+}
+
+impl ReplayInput {
+    /// Creates a source that plays a recording from the start.
+    ///
+    /// # Arguments
+    /// * `frames` - The recorded frames, one per physics frame.
+    ///
+    /// # Returns
+    /// * `ReplayInput` - The new source.
+    pub fn new(frames: Vec<InputFrame>) -> Self {
+        Self {
+            frames,
+            position: 0,
+            held_buttons: HashSet::new(),
+        }
+    }
+
+    /// Checks if the whole recording has been played.
+    ///
+    /// # Returns
+    /// * `bool` - True if the replay has finished.
+    pub fn is_finished(&self) -> bool {
+        self.position > self.frames.len()
+    }
+
+    /// Gets the frame being played.
+    ///
+    /// # Returns
+    /// * `Option<&InputFrame>` - The frame, or None before the replay starts or after it ends.
+    fn current(&self) -> Option<&InputFrame> {
+        self.position
+            .checked_sub(1)
+            .and_then(|index| self.frames.get(index))
+    }
+}
+
+impl InputSource for ReplayInput {
+    /// Buttons still held when the recording ends are released so the player is not left
+    /// holding them forever.
+    fn advance(&mut self) -> Vec<(InputAction, bool)> {
+        if self.is_finished() {
+            return Vec::new();
+        }
+
+        self.position += 1;
+        let Some(frame) = self.current() else {
+            return self
+                .held_buttons
+                .drain()
+                .map(|button| (button, false))
+                .collect();
+        };
+
+        let buttons = frame.buttons.clone();
+        for (button, pressed) in buttons.iter().copied() {
+            if pressed {
+                self.held_buttons.insert(button);
+            } else {
+                self.held_buttons.remove(&button);
+            }
+        }
+        buttons
+    }
+
+    fn horizontal_movement(&self) -> f32 {
+        self.current().map_or(0.0, |frame| frame.horizontal)
+    }
+
+    fn vertical_movement(&self) -> f32 {
+        self.current().map_or(0.0, |frame| frame.vertical)
+    }
+
+    fn aim_direction(&self, _origin: Vector2, _cursor: Vector2) -> Vector2 {
+        self.current().map_or(Vector2::ZERO, |frame| frame.aim)
+    }
+
+    fn burn_strength(&self, metal: MetalType) -> f32 {
+        self.current()
+            .map_or(0.0, |frame| frame.burn_strength(metal))
+    }
+}
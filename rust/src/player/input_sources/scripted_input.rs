@@ -0,0 +1,138 @@
+//! scripted_input.rs
+//!
+//! This file contains the ScriptedInput source which plays back input frames queued by a script.
+//! Scripts can queue a whole sequence of frames up front, for example to drive a player through a
+//! tutorial or a test level, or feed one frame at a time from their own logic. A fed frame replaces
+//! the sticks and triggers of any fed frame that has not been played yet, so a script feeding
+//! faster than the game plays the frames does not build up a backlog, while the buttons of the
+//! replaced frame are still played so no press or release is lost.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use std::collections::VecDeque;
+
+use godot::prelude::*;
+
+use crate::player::enums::input_action::InputAction;
+use crate::player::enums::metal_type::MetalType;
+use crate::player::input_frame::InputFrame;
+use crate::player::traits::input_source::InputSource;
+
+/// Input that is queued frame by frame by a script.
+#[derive(Default)]
+pub struct ScriptedInput {
+    /// The frames that have not been played yet.
+    queue: VecDeque<InputFrame>,
+    /// The frame fed by a script that is played once the queue runs dry.
+    pending: Option<InputFrame>,
+    /// The frame being played. Once the queue runs dry the sticks and triggers of the last frame
+    /// are held so a script does not have to repeat a frame to keep the player running.
+    current: InputFrame,
+}
+
+impl ScriptedInput {
+    /// Creates a source that plays a sequence of frames.
+    ///
+    /// # Arguments
+    /// * `frames` - The frames to play, one per physics frame.
+    ///
+    /// # Returns
+    /// * `ScriptedInput` - The new source.
+    pub fn new(frames: impl IntoIterator<Item = InputFrame>) -> Self {
+        Self {
+            queue: frames.into_iter().collect(),
+            pending: None,
+            current: InputFrame::default(),
+        }
+    }
+
+    /// Checks if every queued and fed frame has been played.
+    ///
+    /// # Returns
+    /// * `bool` - True if there are no frames left to play.
+    pub fn is_finished(&self) -> bool {
+        self.queue.is_empty() && self.pending.is_none()
+    }
+}
+
+impl InputSource for ScriptedInput {
+    fn advance(&mut self) -> Vec<(InputAction, bool)> {
+        match self.queue.pop_front().or_else(|| self.pending.take()) {
+            Some(frame) => {
+                self.current = frame;
+                self.current.buttons.clone()
+            }
+            None => Vec::new(),
+        }
+    }
+
+    fn push_frame(&mut self, mut frame: InputFrame) {
+        // Frames only hold the buttons that changed, so dropping them would lose presses and
+        // leave buttons stuck down
+        if let Some(unplayed) = self.pending.take() {
+            let mut buttons = unplayed.buttons;
+            buttons.append(&mut frame.buttons);
+            frame.buttons = buttons;
+        }
+        self.pending = Some(frame);
+    }
+
+    fn horizontal_movement(&self) -> f32 {
+        self.current.horizontal
+    }
+
+    fn vertical_movement(&self) -> f32 {
+        self.current.vertical
+    }
+
+    fn aim_direction(&self, _origin: Vector2, _cursor: Vector2) -> Vector2 {
+        self.current.aim
+    }
+
+    fn burn_strength(&self, metal: MetalType) -> f32 {
+        self.current.burn_strength(metal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::enums::player_events::PlayerEvents;
+
+    const JUMP: InputAction = InputAction::Player(PlayerEvents::Jump);
+
+    #[test]
+    fn replaced_frame_keeps_its_buttons() {
+        let mut source = ScriptedInput::default();
+        source.push_frame(InputFrame {
+            horizontal: 1.0,
+            buttons: vec![(JUMP, true)],
+            ..InputFrame::default()
+        });
+        source.push_frame(InputFrame {
+            horizontal: -1.0,
+            buttons: vec![(JUMP, false)],
+            ..InputFrame::default()
+        });
+
+        assert_eq!(source.advance(), vec![(JUMP, true), (JUMP, false)]);
+        assert_eq!(source.horizontal_movement(), -1.0);
+        assert!(source.is_finished());
+    }
+
+    #[test]
+    fn queued_frames_play_before_fed_frames() {
+        let mut source = ScriptedInput::new([InputFrame {
+            buttons: vec![(JUMP, true)],
+            ..InputFrame::default()
+        }]);
+        source.push_frame(InputFrame {
+            buttons: vec![(JUMP, false)],
+            ..InputFrame::default()
+        });
+
+        assert_eq!(source.advance(), vec![(JUMP, true)]);
+        assert_eq!(source.advance(), vec![(JUMP, false)]);
+        assert!(source.advance().is_empty());
+    }
+}
@@ -0,0 +1,163 @@
+//! keyboard_profile.rs
+//!
+//! This file contains the keyboard and mouse input profile. Godot gives keyboard and mouse events
+//! the same device id as the first gamepad, so keyboard and mouse input is given its own device id
+//! here so one keyboard player can play alongside gamepad players. The profile also adds the
+//! default keyboard and mouse bindings to the input map. Steel and iron are burned with the mouse
+//! buttons and the mouse cursor selects metal lines.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use godot::classes::{InputEvent, InputEventKey, InputEventMouse, InputEventMouseButton, InputMap};
+use godot::global::{Key, MouseButton};
+use godot::prelude::*;
+
+use super::enums::metal_type::MetalType;
+
+/// The device id given to keyboard and mouse input
+pub const KEYBOARD_DEVICE_ID: i32 = -2;
+
+/// The actions the keyboard player moves with, as they can not share the per device movement
+/// actions of the gamepads
+const MOVE_LEFT_ACTION: &str = "move_left_keyboard";
+const MOVE_RIGHT_ACTION: &str = "move_right_keyboard";
+const MOVE_UP_ACTION: &str = "move_up_keyboard";
+const MOVE_DOWN_ACTION: &str = "move_down_keyboard";
+
+/// The default keys of each action
+const KEY_BINDINGS: &[(&str, Key)] = &[
+    (MOVE_LEFT_ACTION, Key::A),
+    (MOVE_RIGHT_ACTION, Key::D),
+    (MOVE_UP_ACTION, Key::W),
+    (MOVE_DOWN_ACTION, Key::S),
+    ("jump", Key::SPACE),
+    ("roll", Key::C),
+    ("sprint", Key::SHIFT),
+    ("attack", Key::Q),
+    ("low_burn", Key::CTRL),
+    ("throw", Key::G),
+    ("metal_selector", Key::ALT),
+    ("pewter", Key::E),
+    ("tin", Key::KEY_1),
+    ("copper", Key::KEY_2),
+    ("bronze", Key::KEY_3),
+];
+
+/// The default mouse buttons of each action
+const MOUSE_BINDINGS: &[(&str, MouseButton)] = &[
+    ("steel", MouseButton::LEFT),
+    ("iron", MouseButton::RIGHT),
+    ("lock_on", MouseButton::MIDDLE),
+];
+
+/// The keyboard and mouse input profile.
+pub struct KeyboardProfile;
+
+impl KeyboardProfile {
+    /// Adds the default keyboard and mouse bindings to the input map. Actions that already have
+    /// a keyboard or mouse binding in the project settings keep it.
+    pub fn register_actions() {
+        let mut input_map = InputMap::singleton();
+
+        for (action, key) in KEY_BINDINGS {
+            let mut event = InputEventKey::new_gd();
+            event.set_physical_keycode(*key);
+            Self::add_binding(&mut input_map, action, event.upcast());
+        }
+
+        for (action, button) in MOUSE_BINDINGS {
+            let mut event = InputEventMouseButton::new_gd();
+            event.set_button_index(*button);
+            Self::add_binding(&mut input_map, action, event.upcast());
+        }
+    }
+
+    /// Gets the device an input event came from, giving keyboard and mouse events their own
+    /// device id.
+    ///
+    /// # Arguments
+    /// * `event` - The input event.
+    ///
+    /// # Returns
+    /// * `i32` - The device id of the event.
+    pub fn event_device(event: &Gd<InputEvent>) -> i32 {
+        if Self::is_keyboard_event(event) {
+            KEYBOARD_DEVICE_ID
+        } else {
+            event.get_device()
+        }
+    }
+
+    /// Gets the actions a player moves left and right with.
+    ///
+    /// # Arguments
+    /// * `device_id` - The device of the player.
+    ///
+    /// # Returns
+    /// * `(StringName, StringName)` - The move left and move right actions.
+    pub fn horizontal_actions(device_id: i32) -> (StringName, StringName) {
+        if device_id == KEYBOARD_DEVICE_ID {
+            (MOVE_LEFT_ACTION.into(), MOVE_RIGHT_ACTION.into())
+        } else {
+            (
+                format!("move_left{}", device_id).into(),
+                format!("move_right{}", device_id).into(),
+            )
+        }
+    }
+
+    /// Gets the actions the keyboard player looks up and down with.
+    ///
+    /// # Returns
+    /// * `(StringName, StringName)` - The move up and move down actions.
+    pub fn vertical_actions() -> (StringName, StringName) {
+        (MOVE_UP_ACTION.into(), MOVE_DOWN_ACTION.into())
+    }
+
+    /// Gets the mouse button a metal is burned with, which sets how hard the metal is burned.
+    ///
+    /// # Arguments
+    /// * `metal` - The metal being burned.
+    ///
+    /// # Returns
+    /// * `Option<MouseButton>` - The mouse button, if the metal is burned with the mouse.
+    pub fn burn_button(metal: MetalType) -> Option<MouseButton> {
+        MOUSE_BINDINGS
+            .iter()
+            .find(|(action, _)| *action == metal.as_str())
+            .map(|(_, button)| *button)
+    }
+
+    /// Checks if an input event came from the keyboard or mouse.
+    ///
+    /// # Arguments
+    /// * `event` - The input event.
+    ///
+    /// # Returns
+    /// * `bool` - True if the event came from the keyboard or mouse.
+    fn is_keyboard_event(event: &Gd<InputEvent>) -> bool {
+        event.clone().try_cast::<InputEventKey>().is_ok()
+            || event.clone().try_cast::<InputEventMouse>().is_ok()
+    }
+
+    /// Adds a keyboard or mouse binding to an action, creating the action if it does not exist.
+    ///
+    /// # Arguments
+    /// * `input_map` - The input map.
+    /// * `action` - The action to bind.
+    /// * `event` - The key or mouse button to bind to the action.
+    fn add_binding(input_map: &mut Gd<InputMap>, action: &str, event: Gd<InputEvent>) {
+        let action = StringName::from(action);
+        if !input_map.has_action(&action) {
+            input_map.add_action(&action);
+        }
+
+        let already_bound = input_map
+            .action_get_events(&action)
+            .iter_shared()
+            .any(|bound| Self::is_keyboard_event(&bound));
+        if !already_bound {
+            input_map.action_add_event(&action, &event);
+        }
+    }
+}
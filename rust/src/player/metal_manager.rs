@@ -137,6 +137,7 @@ impl MetalManager {
                 100.0,
                 100.0,
                 0.15,
+                0.08,
                 player.clone(),
                 MetalType::Copper,
             )),
@@ -2,18 +2,26 @@
 //!
 //! This file contains the implementation of the Bronze player ability.
 //! The Bronze ability allows players to view the particles that other players emit when they burn
-//! metals, given that the other player is not burning copper.
+//! metals, given that the other player is not hidden inside a coppercloud.
 //!
 //! Author: Michael Imerman, Charles Barth
 //! Version: Spring 2025
+use crate::game::Game;
+use crate::player::bronze_radar::RadarPulse;
 use crate::player::enums::metal_type::MetalType;
 use crate::player::player::Player;
 use crate::player::traits::metal::Metal;
-use godot::obj::{Gd, GdMut};
+use godot::obj::{Gd, GdMut, WithBaseField};
+
+/// The distance in pixels that pulses can be sensed from while burning bronze
+const BURN_RADAR_RANGE: f32 = 2400.0;
+/// The distance in pixels that pulses can be sensed from while low burning bronze
+const LOW_BURN_RADAR_RANGE: f32 = 1200.0;
 
 /// The Bronze player ability.
 /// This ability allows players to view the particles that other players emit when they low burn
-/// metals, given that the other player is not burning copper.
+/// metals, given that the other player is not hidden inside a coppercloud the bronze burner is
+/// outside of.
 pub struct Bronze {
     /// The maximum amount of bronze the player can store.
     capacity: f64,
@@ -66,14 +74,60 @@ impl Bronze {
     }
 
     /// Function that updates particle visibility for nearby players
+    /// Players concealed by a coppercloud that this player is not inside of stay hidden.
     fn update_particle_visibility(&mut self) {
         let mut player = self.player.bind_mut();
         let visibility_mask = 1 << player.get_player_id() * 2;
+        let observer_position = player.base().get_global_position();
+        let game = player.base().get_node_as::<Game>("/root/Game");
+        let game = game.bind();
+
         let nearby_players = player.get_nearby_players();
         for other_player in nearby_players.iter_mut() {
+            let concealed =
+                game.is_concealed_from(other_player.get_global_position(), observer_position);
             let mut other_player = other_player.bind_mut();
-            other_player.reveal_particles(visibility_mask);
+            if concealed {
+                other_player.hide_particles(visibility_mask);
+            } else {
+                other_player.reveal_particles(visibility_mask);
+            }
+        }
+    }
+
+    /// Function that updates the bronze radar with the pulses of every player burning metals in
+    /// range. Players concealed by a coppercloud that this player is not inside of are left out.
+    ///
+    /// # Arguments
+    /// * `range` - The distance in pixels that pulses can be sensed from.
+    fn update_radar(&mut self, range: f32) {
+        let mut player = self.player.bind_mut();
+        let player_instance = player.base().instance_id();
+        let observer_position = player.base().get_global_position();
+        let game = player.base().get_node_as::<Game>("/root/Game");
+        let game = game.bind();
+
+        let mut pulses = Vec::new();
+        for other_player in game.get_players() {
+            if other_player.instance_id() == player_instance {
+                continue;
+            }
+
+            let position = other_player.get_global_position();
+            let offset = position - observer_position;
+            if offset.length() > range || game.is_concealed_from(position, observer_position) {
+                continue;
+            }
+
+            let metals = other_player.bind().get_active_metals().clone();
+            if metals.is_empty() {
+                continue;
+            }
+
+            pulses.push(RadarPulse { offset, metals });
         }
+
+        player.get_bronze_radar().bind_mut().set_pulses(pulses);
     }
 
     /// Function that hides particle visibility for nearby players
@@ -86,6 +140,8 @@ impl Bronze {
             let mut other_player = other_player.bind_mut();
             other_player.hide_particles(visibility_mask);
         }
+
+        player.get_bronze_radar().bind_mut().clear();
     }
 }
 
@@ -93,16 +149,18 @@ impl Bronze {
 /// Metal methods for Bronze
 impl Metal for Bronze {
     /// The burn function for bronze.
-    /// It does the same as low_burn because copper has static performance.
+    /// Reveals nearby particles and senses pulses from far away on the radar.
     fn burn(&mut self) {
         self.update_particle_visibility();
+        self.update_radar(BURN_RADAR_RANGE);
     }
 
     /// The low burn function for bronze.
-    /// Sets the low_burning flag to true and shows the copper particles.
+    /// Reveals nearby particles and senses pulses on the radar with a shorter range than burning.
     fn low_burn(&mut self) {
         if !self.burning {
             self.update_particle_visibility();
+            self.update_radar(LOW_BURN_RADAR_RANGE);
         }
     }
 
@@ -1,25 +1,34 @@
 //! copper.rs
 //!
 //! This file contains the implementation of the Copper player ability.
-//! The Copper ability allows players to mask their particles from other players that are burning
-//! Bronze.
+//! The Copper ability creates a coppercloud around the player that masks the particles and pulses
+//! of everyone inside it from players that are burning Bronze outside of it.
 //!
 //! Author: Michael Imerman, Charles Barth
 //! Version: Spring 2025
-use godot::obj::{Gd, GdMut};
+use godot::obj::{Gd, GdMut, WithBaseField};
 
+use crate::game::Game;
 use crate::player::enums::metal_type::MetalType;
 use crate::player::player::Player;
 use crate::player::traits::metal::Metal;
 
+/// The radius of the coppercloud while burning copper
+const BURN_CLOUD_RADIUS: f32 = 320.0;
+/// The radius of the coppercloud while low burning copper
+const LOW_BURN_CLOUD_RADIUS: f32 = 160.0;
+
 /// The Copper player ability.
-/// This ability allows players to mask their particles from other players.
-/// This ability will counter Bronze, which allows players to see other players' particles.
+/// This ability creates a coppercloud around the player.
+/// Bronze burners outside the cloud can not sense the particles or pulses of anyone inside it.
+/// The cloud is drawn for the burner and their team so they know where it ends.
 pub struct Copper {
     /// The maximum amount of copper the player can store.
     capacity: f64,
     /// The current amount of copper the player has.
     current_reserve: f64,
+    /// The rate at which the player burns copper.
+    burn_rate: f64,
     /// The rate at which the player burns copper when using the low burn ability.
     low_burn_rate: f64,
     /// A flag to determine if the player is low burning.
@@ -39,6 +48,7 @@ impl Copper {
     /// # Arguments
     /// * `capacity` - The maxiumum amount of copper the player can store 
     /// * `current_reserve` - The current amount of copper the player has
+    /// * `burn_rate` - The rate at which the player burns copper
     /// * `low_burn_rate` - The rate at which the player burns copper when using
     ///                     the low burn ability
     /// * `player` - A reference to the player
@@ -49,6 +59,7 @@ impl Copper {
     pub fn new(
         capacity: f64,
         current_reserve: f64,
+        burn_rate: f64,
         low_burn_rate: f64,
         player: Gd<Player>,
         metal_type: MetalType,
@@ -57,6 +68,7 @@ impl Copper {
             capacity,
             current_reserve,
             previous_reserve: 0.0,
+            burn_rate,
             low_burn_rate,
             low_burning: false,
             burning: false,
@@ -64,20 +76,50 @@ impl Copper {
             metal_type,
         }
     }
+
+    /// Updates the size of the player's coppercloud.
+    /// The cloud is registered with the game so bronze burners can check it and the cloud's
+    /// outline is made visible to the burner and their teammates.
+    ///
+    /// # Arguments
+    /// * `radius` - The radius of the cloud, 0 removes the cloud.
+    fn update_cloud(&mut self, radius: f32) {
+        let player_gd = self.player.clone();
+        let mut player = self.player.bind_mut();
+        let player_id = player.get_player_id();
+
+        let mut game = player.base().get_node_as::<Game>("/root/Game");
+        let teammates = game.bind().get_teammate_ids(player_id);
+        game.bind_mut().set_copper_cloud(player_gd, player_id, radius);
+
+        let mut visibility_layer: u32 = 1 << (player_id * 2);
+        for teammate_id in teammates {
+            visibility_layer |= 1 << (teammate_id * 2);
+        }
+
+        let mut cloud = player.get_copper_cloud();
+        cloud.set_visibility_layer(visibility_layer);
+        cloud.bind_mut().set_radius(radius);
+    }
 }
 
 /// Metal methods for Copper
 impl Metal for Copper {
     /// The burn function for copper.
-    /// It does the same as low_burn because copper has static performance.
+    /// Surrounds the player with a full sized coppercloud.
     fn burn(&mut self) {
-        return;
+        self.update_reserve(-self.burn_rate);
+        self.update_cloud(BURN_CLOUD_RADIUS);
     }
 
     /// The low burn function for copper.
-    /// Sets the low_burning flag to true. No particles are shown for copper.
+    /// Surrounds the player with a smaller coppercloud unless a full burn is also active.
     fn low_burn(&mut self) {
-        return;
+        self.update_reserve(-self.low_burn_rate);
+
+        if !self.burning {
+            self.update_cloud(LOW_BURN_CLOUD_RADIUS);
+        }
     }
 
     /// This function will update the total metal reserve for copper.
@@ -138,6 +180,7 @@ impl Metal for Copper {
         }
 
         if !self.burning && !self.low_burning {
+            self.update_cloud(0.0);
             // remove the metal from the player's active metals
             let mut player = self.player.bind_mut();
             player.remove_active_metal(self.metal_type);
@@ -159,6 +202,7 @@ impl Metal for Copper {
         }
 
         if !self.low_burning && !self.burning {
+            self.update_cloud(0.0);
             // remove the metal from the player's active metals
             let mut player = self.player.bind_mut();
             player.remove_active_metal(self.metal_type);
@@ -13,7 +13,8 @@ use crate::player::player::Player;
 use crate::player::traits::metal::Metal;
 
 /// The pewter player ability.
-/// Currently this gives the player a speed boost and a jump boost.
+/// Currently this gives the player a speed boost and a jump boost and suppresses fatigue.
+/// Running out of pewter while sprinting or rolling causes the player to collapse into pewter drag.
 /// In the future we would like to reduce these boost and add additional mechanics.
 /// Such as the ability to wall jump or chain a landing into a high jump.
 pub struct Pewter {
@@ -86,6 +87,33 @@ impl Pewter {
                 .remove_force_modifier(ForceModifierTag::Pewter);
         }
     }
+
+    /// This function will adjust how much stamina the player's actions cost.
+    /// Burning pewter removes fatigue entirely while low burning halves it.
+    fn adjust_fatigue_scale(&mut self) {
+        let fatigue_scale = if self.burning {
+            0.0
+        } else if self.low_burning {
+            0.5
+        } else {
+            1.0
+        };
+
+        self.player.bind_mut().set_fatigue_scale(fatigue_scale);
+    }
+
+    /// This function will collapse the player into pewter drag if they have just run out of
+    /// pewter while exerting themselves.
+    fn check_for_crash(&mut self) {
+        if self.current_reserve > 0.0 {
+            return;
+        }
+
+        let mut player = self.player.bind_mut();
+        if player.is_exerting() {
+            player.pewter_crash();
+        }
+    }
 }
 
 /// Metal methods for Pewter
@@ -97,6 +125,7 @@ impl Metal for Pewter {
     /// * `player` - A mutable reference to the player so that the run speed and jump force can be modified.
     fn burn(&mut self) {
         self.update_reserve(-self.burn_rate);
+        self.check_for_crash();
     }
 
     /// The low burn function for pewter.
@@ -106,6 +135,7 @@ impl Metal for Pewter {
     /// * `player` - A mutable reference to the player so that the run speed and jump force can be modified.
     fn low_burn(&mut self) {
         self.update_reserve(-self.low_burn_rate);
+        self.check_for_crash();
     }
 
     /// This function will update the reserve of the pewter.
@@ -167,6 +197,8 @@ impl Metal for Pewter {
                 jump_boost: 0.2,
             });
         }
+
+        self.adjust_fatigue_scale();
     }
 
     /// This function will set the low burning flag.
@@ -187,6 +219,8 @@ impl Metal for Pewter {
                 jump_boost: 0.5,
             });
         }
+
+        self.adjust_fatigue_scale();
     }
 
     /// This function will get the player.
@@ -8,14 +8,13 @@
 //! Author: Charles Barth, Michael Imerman
 //! Version: Spring 2025
 use godot::builtin::{Color, Vector2};
-use godot::classes::Input;
-use godot::global::JoyAxis;
 use godot::obj::WithBaseField;
 use godot::prelude::*;
 
 use crate::metal_object::MetalObject;
 use crate::player::enums::force::Force;
-use crate::player::enums::metal_type::MetalType;
+use crate::player::enums::metal_type::{BurnType, MetalType};
+use crate::player::enums::player_events::PlayerEvents;
 use crate::player::player::Player;
 use crate::player::traits::metal::Metal;
 
@@ -27,6 +26,8 @@ const PUSH_BURN_DIRECTION: f32 = 1.0;
 /// If the player pulls on a metal object then the player will be pulled in the direction of the metal object.
 /// This done by calculating the angle between the player and the metal object and then applying a percentage of the max acceleration
 /// based on how far off the angle is from either 0, 90, 180, or 270 degrees.
+/// Targets are selected by aiming, or can be locked on to and cycled through while the lock on
+/// button is held.
 pub struct Steel {
     /// The maximum amount of steel the player can store.
     capacity: f64,
@@ -52,6 +53,13 @@ pub struct Steel {
     player: Gd<Player>,
     /// The type of metal.
     metal_type: MetalType,
+    /// A flag to determine if the player has locked on to their current target.
+    locked: bool,
+    /// The metal objects that can be cycled through while locked on.
+    /// These are ordered by how close they were to the player's aim and then by distance.
+    lock_candidates: Vec<Gd<MetalObject>>,
+    /// The index of the current target in the lock candidates.
+    lock_index: usize,
 }
 
 impl Metal for Steel {
@@ -67,25 +75,31 @@ impl Metal for Steel {
         let mut player_clone = self.player.clone();
         let mut player = player_clone.bind_mut();
 
+        let range = self.sensing_range(&player);
         if self.object.is_none() {
             return;
-        } else if !player.is_metal_object_in_range(self.object.as_ref().unwrap()) {
+        } else if !player.can_sense_metal_object(self.object.as_ref().unwrap(), range) {
             self.object = None;
             self.object_location = Vector2::ZERO;
             return;
         }
 
+        // Pushes weaken the further away the metal object is
+        let distance = player
+            .base()
+            .get_global_position()
+            .distance_to(self.object.as_ref().unwrap().get_global_position());
+        let min_falloff = player.get_settings().bind().get_min_push_falloff();
+        let falloff = 1.0 - (1.0 - min_falloff) * (distance / range).clamp(0.0, 1.0);
+
         self.update_reserve(-self.burn_rate);
 
         // TODO: Make constant
         let max_acceleration: f32 = 200.0;
-        let trigger = if self.metal_type == MetalType::Steel {
-            JoyAxis::TRIGGER_RIGHT
-        } else {
-            JoyAxis::TRIGGER_LEFT
-        };
-
-        let strength = Input::singleton().get_joy_axis(player.get_device_id(), trigger);
+        let strength = player
+            .get_input_manager()
+            .bind()
+            .get_burn_strength(self.metal_type);
 
         // If the player is not on the floor, try to update their up direction based on nearby surfaces.
         if !player.base().is_on_floor() {
@@ -95,9 +109,9 @@ impl Metal for Steel {
         // Use the x and y components directly since 'direction' is normalized.
 
         let x_acceleration =
-            max_acceleration * self.object_location.x * self.burn_direction * strength;
+            max_acceleration * self.object_location.x * self.burn_direction * strength * falloff;
         let y_acceleration =
-            max_acceleration * self.object_location.y * self.burn_direction * strength;
+            max_acceleration * self.object_location.y * self.burn_direction * strength * falloff;
 
         let metal_object = self.object.as_mut().unwrap();
         let mut bound_metal = metal_object.bind_mut();
@@ -114,8 +128,9 @@ impl Metal for Steel {
     /// * `player` - A mutable reference to the player so that the metal line can be modified.
     fn low_burn(&mut self) {
         self.update_reserve(-self.low_burn_rate);
+        self.update_lock();
 
-        if self.burn {
+        if self.burn || self.locked {
             self.update_selected_object_location();
         } else {
             self.update_line_selection();
@@ -252,7 +267,146 @@ impl Steel {
             burn: false,
             player,
             metal_type,
+            locked: false,
+            lock_candidates: Vec::new(),
+            lock_index: 0,
+        }
+    }
+
+    /// This function will update the lock on state of the player.
+    /// Pressing the lock on button locks the current target, the lock persists while the button is
+    /// held and the cycle target button moves the lock on to the next candidate.
+    fn update_lock(&mut self) {
+        let mut input_manager_unbound = self.player.bind_mut().get_input_manager();
+        let mut input_manager = input_manager_unbound.bind_mut();
+
+        let lock_held = input_manager.check_for_player_event(PlayerEvents::LockOn);
+        let cycle_target =
+            self.locked && input_manager.fetch_player_event(PlayerEvents::CycleTarget);
+        let aim_direction = input_manager.get_aim_direction();
+        drop(input_manager);
+
+        if !lock_held {
+            self.release_lock();
+            return;
+        }
+
+        if !self.locked {
+            self.lock_on(aim_direction);
+            return;
+        }
+
+        self.prune_lock_candidates();
+
+        if cycle_target && !self.lock_candidates.is_empty() {
+            self.lock_index = (self.lock_index + 1) % self.lock_candidates.len();
+            self.object = Some(self.lock_candidates[self.lock_index].clone());
+        }
+    }
+
+    /// This function will lock on to the current target.
+    /// If there is no current target the candidate closest to the player's aim is locked on to.
+    ///
+    /// # Arguments
+    /// * `aim_direction` - The direction the player is aiming in.
+    fn lock_on(&mut self, aim_direction: Vector2) {
+        let player = self.player.bind();
+        let player_position = player.base().get_global_position();
+        let range = self.sensing_range(&player);
+
+        let mut candidates: Vec<(f32, f32, Gd<MetalObject>)> = player
+            .get_metal_objects()
+            .iter()
+            .filter(|metal_object| player.can_sense_metal_object(metal_object, range))
+            .map(|metal_object| {
+                let offset = metal_object.get_global_position() - player_position;
+                let angle_diff = if aim_direction == Vector2::ZERO {
+                    0.0
+                } else {
+                    aim_direction.angle_to(offset.normalized_or_zero()).abs()
+                };
+
+                (angle_diff, offset.length(), metal_object.clone())
+            })
+            .collect();
+        drop(player);
+
+        if candidates.is_empty() {
+            return;
         }
+
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+        self.lock_candidates = candidates
+            .into_iter()
+            .map(|(_, _, metal_object)| metal_object)
+            .collect();
+
+        self.lock_index = self
+            .object
+            .as_ref()
+            .and_then(|object| self.lock_candidates.iter().position(|c| c == object))
+            .unwrap_or(0);
+        self.object = Some(self.lock_candidates[self.lock_index].clone());
+        self.locked = true;
+    }
+
+    /// This function will remove lock candidates that are no longer in range.
+    /// If the current target left range the lock moves to the next candidate or is released if
+    /// none are left.
+    fn prune_lock_candidates(&mut self) {
+        let player = self.player.bind();
+        let range = self.sensing_range(&player);
+        self.lock_candidates
+            .retain(|metal_object| player.can_sense_metal_object(metal_object, range));
+        drop(player);
+
+        if self.lock_candidates.is_empty() {
+            self.release_lock();
+            self.object = None;
+            self.object_location = Vector2::ZERO;
+            return;
+        }
+
+        let target_in_range = self
+            .object
+            .as_ref()
+            .and_then(|object| self.lock_candidates.iter().position(|c| c == object));
+
+        match target_in_range {
+            Some(index) => self.lock_index = index,
+            None => {
+                self.lock_index %= self.lock_candidates.len();
+                self.object = Some(self.lock_candidates[self.lock_index].clone());
+            }
+        }
+    }
+
+    /// This function will get the range metal objects can be sensed from.
+    /// Burning uses the burn range while low burning on its own uses the low burn range.
+    ///
+    /// # Arguments
+    /// * `player` - The player burning the metal.
+    ///
+    /// # Returns
+    /// * `f32` - The range in pixels.
+    fn sensing_range(&self, player: &Player) -> f32 {
+        let burn_type = if self.burn {
+            BurnType::Burn
+        } else {
+            BurnType::LowBurn
+        };
+
+        player
+            .get_settings()
+            .bind()
+            .get_metal_range(self.metal_type, burn_type)
+    }
+
+    /// This function will release the lock on so the target follows the player's aim again.
+    fn release_lock(&mut self) {
+        self.locked = false;
+        self.lock_candidates.clear();
+        self.lock_index = 0;
     }
 
     /// Checks if the passed metal object is the closest metal object to the joystick angle.
@@ -275,7 +429,7 @@ impl Steel {
         metal_object_position: Vector2,
     ) -> Option<(Vector2, f32)> {
         // Return the current object location and angle difference if joystick is in the deadzone.
-        if joy_position.length() < 0.2 {
+        if joy_position == Vector2::ZERO {
             return None;
         }
 
@@ -306,8 +460,15 @@ impl Steel {
         let mut index_closest_metal_object = usize::MAX;
         let mut points = PackedVector2Array::new();
         let mut colors = PackedColorArray::new();
+        let range = self.sensing_range(&player);
+
+        for metal_object in player.get_metal_objects().iter() {
+            // Objects out of range or behind walls do not get a line
+            if !player.can_sense_metal_object(metal_object, range) {
+                continue;
+            }
 
-        for (index, metal_object) in player.get_metal_objects().iter().enumerate() {
+            let index = colors.len();
             let color = Color::from_rgba(0.0, 0.4, 0.9, 0.1);
             let metal_object_position = bound_metal_line
                 .base()
@@ -351,12 +512,8 @@ impl Steel {
         let player_position = metal_line.to_local(metal_line.get_global_position());
         let mut bound_metal_line = metal_line.bind_mut();
 
-        // Get the joystick position.
-        let joy_position_x =
-            Input::singleton().get_joy_axis(player.get_device_id(), JoyAxis::RIGHT_X);
-        let joy_position_y =
-            Input::singleton().get_joy_axis(player.get_device_id(), JoyAxis::RIGHT_Y);
-        let joy_position = Vector2::new(joy_position_x, joy_position_y);
+        // Get the direction the player is aiming in, this is the joystick or the mouse cursor.
+        let joy_position = player.get_input_manager().bind().get_aim_direction();
 
         // A metal object must be within ±25 degrees to be selected.
         let mut closest_obj_angle_diff: f32 = 40.0_f32.to_radians();
@@ -365,8 +522,15 @@ impl Steel {
         let mut index_closest_metal_object = usize::MAX;
         let mut points = PackedVector2Array::new();
         let mut colors = PackedColorArray::new();
+        let range = self.sensing_range(&player);
+
+        for metal_object in player.get_metal_objects().iter() {
+            // Objects out of range or behind walls do not get a line
+            if !player.can_sense_metal_object(metal_object, range) {
+                continue;
+            }
 
-        for (index, metal_object) in player.get_metal_objects().iter().enumerate() {
+            let index = colors.len();
             let color = Color::from_rgba(0.0, 0.4, 0.9, 0.1);
             let metal_object_position = bound_metal_line
                 .base()
@@ -405,6 +569,7 @@ impl Steel {
     fn cleanup_low_burn(&mut self) {
         self.object_location = Vector2::ZERO;
         self.object = None;
+        self.release_lock();
 
         let mut player = self.player.bind_mut();
         player
@@ -15,13 +15,16 @@ use std::time::Instant;
 use godot::classes::Camera2D;
 use godot::classes::CanvasItem;
 use godot::classes::CharacterBody2D;
+use godot::classes::CollisionShape2D;
 use godot::classes::ConfigFile;
 use godot::classes::Control;
 use godot::classes::Engine;
 use godot::classes::GpuParticles2D;
 use godot::classes::ICharacterBody2D;
-use godot::classes::Input;
+use godot::classes::PhysicsRayQueryParameters2D;
 use godot::classes::PointLight2D;
+use godot::classes::RayCast2D;
+use godot::classes::RectangleShape2D;
 use godot::classes::Sprite2D;
 use godot::classes::SubViewport;
 use godot::classes::TextureProgressBar;
@@ -35,7 +38,14 @@ use crate::player::player_tin_light::PlayerTinLight;
 use crate::settings::Settings;
 use crate::ui::metal_reserve_bar_manager::MetalReserveBarManager;
 
+use super::attack_data::AttackData;
+use super::bronze_radar::BronzeRadar;
+use super::copper_cloud::CopperCloud;
+use super::damage_event::{DamageEvent, DamageRecord};
+use super::damage_resolver::DamageResolver;
 use super::disconnected::Disconnected;
+use super::enums::attack_kind::AttackKind;
+use super::enums::damage_type::DamageType;
 use super::enums::force::Force;
 use super::enums::force::ForceModifier;
 use super::enums::force::ForceModifierTag;
@@ -45,6 +55,7 @@ use super::enums::timeout_events::TimeoutEvents;
 use super::input_manager::InputManager;
 use super::metal_line::MetalLine;
 use super::metal_manager::MetalManager;
+use super::state_record::StateRecord;
 use crate::ui::coin_counter::CoinCounter;
 
 /// The maximum amount of health the player can have
@@ -63,6 +74,29 @@ const MIN_RUN_SPEED: f32 = 0.0;
 const MAX_JUMP_FORCE: f32 = 700.0;
 /// The minimum jump force of the player
 const MIN_JUMP_FORCE: f32 = 300.0;
+/// The number of state transitions kept in the player's state history
+const STATE_HISTORY_LENGTH: usize = 32;
+/// The physics layer only the map's tiles are on, used for line of sight and ledge checks
+const TILE_COLLISION_LAYER: u32 = 1 << 10;
+/// The speed a player has to hit the ground or a wall at to be hurt by the impact
+const HARD_IMPACT_SPEED: f32 = 900.0;
+/// The damage dealt by an impact for each unit of speed above the hard impact speed
+const IMPACT_DAMAGE_PER_SPEED: f64 = 0.1;
+/// How far in front of the player a ledge can be grabbed from
+const LEDGE_REACH: f32 = 14.0;
+/// The number of damage records kept for crediting kills and assists
+const DAMAGE_HISTORY_LENGTH: usize = 8;
+/// How long a player is held responsible for environmental damage after sending something into
+/// this player
+const PUSHER_MEMORY: Duration = Duration::from_secs(3);
+/// The maximum amount of stamina the player can have
+const MAX_STAMINA: f64 = 100.0;
+/// The amount of stamina the player regains per second while not exerting themselves
+const STAMINA_REGEN_RATE: f64 = 20.0;
+/// The fraction of the default run speed the player keeps while suffering from pewter drag
+const PEWTER_DRAG_RUN_SCALE: f32 = 0.5;
+/// The fraction of the default jump force the player keeps while suffering from pewter drag
+const PEWTER_DRAG_JUMP_SCALE: f32 = 0.7;
 
 // Add an enum to identify different node types
 #[derive(Hash, Eq, PartialEq, Clone, Debug, Copy)]
@@ -85,6 +119,10 @@ enum CachedNode {
     Sprite,
     IronParticles,
     CopperParticles,
+    CopperCloud,
+    BronzeRadar,
+    LedgeWallRay,
+    LedgeHeadRay,
 }
 
 #[derive(GodotClass)]
@@ -100,12 +138,21 @@ pub struct Player {
     default_gravity: f64,
     /// The health of the player
     health: f64,
+    /// The stamina of the player, used up by sprinting and rolling
+    stamina: f64,
+    /// A multiplier applied to stamina costs, pewter lowers this to suppress fatigue
+    fatigue_scale: f64,
     /// The amount of time that has passed since the last frame
     delta: f64,
     /// The current state of the player
     current_state: PlayerStates,
     /// The previous state of the player
     previous_state: PlayerStates,
+    /// The most recent state transitions of the player, oldest first
+    state_history: VecDeque<StateRecord>,
+    /// The input event that triggered the transition the player is about to make, if the
+    /// transition table knows it
+    transition_trigger: Option<PlayerEvents>,
     /// A flag to determine if the player's animation has finished
     anim_finished: bool,
     /// The current maximum run speed of the player
@@ -134,12 +181,24 @@ pub struct Player {
     mass: f32,
     /// If the player is attacking or not
     is_attacking: bool,
+    /// The melee attack the player is doing and when they started it
+    current_attack: Option<(AttackKind, Instant)>,
+    /// The attack the current attack was chained from during its cancel window, if any
+    combo_link: Option<AttackKind>,
+    /// The direction pointing away from the wall the player last slid down
+    wall_direction: f32,
+    /// The global position of the top corner of the ledge the player last grabbed
+    ledge_point: Vector2,
     /// HashMap storing cached node references
     cached_nodes: HashMap<CachedNode, Gd<Node>>,
     /// The settings for the game
     settings: Gd<Settings>,
     /// The number of eliminations the player has
     eliminations: i32,
+    /// The most recent damage the player took from other players, oldest first
+    damage_history: VecDeque<DamageRecord>,
+    /// The player that last sent something into this player and when they did it
+    last_pusher: Option<(Gd<Player>, Instant)>,
     previous_velocity: Vector2,
     /// This is collection of modifier meant to be applied to forces before they're applied
     /// to the player
@@ -170,11 +229,15 @@ impl ICharacterBody2D for Player {
             base,
             direction: 1.0,
             health: MAX_HEALTH,
+            stamina: MAX_STAMINA,
+            fatigue_scale: 1.0,
             delta: 0.0,
             default_gravity: gravity,
             gravity,
             current_state: PlayerStates::Jump,
             previous_state: PlayerStates::Fall,
+            state_history: VecDeque::new(),
+            transition_trigger: None,
             anim_finished: false,
             run_speed: DEFAULT_RUN_SPEED,
             jump_force: DEFAULT_JUMP_FORCE,
@@ -188,9 +251,15 @@ impl ICharacterBody2D for Player {
             current_particles: None,
             mass: 500.0,
             is_attacking: false,
+            current_attack: None,
+            combo_link: None,
+            wall_direction: 0.0,
+            ledge_point: Vector2::ZERO,
             cached_nodes: HashMap::new(),
             settings,
             eliminations: 0,
+            damage_history: VecDeque::new(),
+            last_pusher: None,
             previous_velocity: Vector2::ZERO,
             force_modifiers: HashMap::new(),
         }
@@ -297,7 +366,13 @@ impl ICharacterBody2D for Player {
             .bind_mut()
             .check_for_player_event(PlayerEvents::Die)
         {
-            self.adjust_health(-100.0);
+            let source = self.get_last_pusher();
+            self.take_damage(DamageEvent::new(
+                source,
+                DamageType::Environment,
+                MAX_HEALTH,
+                Vector2::ZERO,
+            ));
         }
 
         self.set_delta(delta);
@@ -305,6 +380,7 @@ impl ICharacterBody2D for Player {
         // Update the current state of the player
         self.current_state.update_state(self);
         self.set_animation_direction();
+        self.regenerate_stamina();
 
         // Check for any timeout events that have expired
         self.expire_timeout_events();
@@ -320,6 +396,7 @@ impl ICharacterBody2D for Player {
         // Make the player move and slide based on their velocity
         self.apply_forces();
         self.base_mut().move_and_slide();
+        self.check_wall_slam();
     }
 }
 
@@ -330,6 +407,13 @@ impl Player {
     /// This will clean up the player and the viewport they are in
     /// as well as notify the game that the player has died
     pub fn die(&mut self) {
+        // Release anything the player's state is holding onto, such as a live hitbox
+        self.current_state.exit_state(self);
+
+        if self.settings.bind().is_debug_mode() {
+            self.dump_state_history();
+        }
+
         let mut camera = Camera2D::new_alloc();
         camera.set_name("OverviewCamera");
         camera.set_position(Vector2::new(20.0, -225.0));
@@ -346,13 +430,7 @@ impl Player {
         parent_viewport.set_canvas_cull_mask(1);
         parent_viewport.add_child(&camera);
         let mut game = self.base().get_node_as::<Game>("/root/Game").clone();
-        game.call_deferred(
-            "remove_player",
-            &[
-                Variant::from(self.player_id),
-                Variant::from(self.eliminations),
-            ],
-        );
+        game.call_deferred("remove_player", &[Variant::from(self.player_id)]);
     }
 
     /// Makes a given player visible to the current player
@@ -387,16 +465,20 @@ impl Player {
     }
 
     /// Set the current state of the player and triggers the enter method of the new state
+    /// The exit method of the current state is triggered first so it can release anything it acquired
+    /// If the new state can not be entered right now the player stays in their current state
     /// This method also sets the previous state of the player to the current state
     /// The enter method of the new state is triggered to allow for any initial and/or one-time logic to be executed
     ///
     /// # Arguments
     /// * `new_state` - The new state to set the player to
     pub fn set_state(&mut self, new_state: PlayerStates) {
-        if self.current_state == new_state {
+        if self.current_state == new_state || !new_state.can_enter_state(self) {
             return;
         }
 
+        self.current_state.exit_state(self);
+        self.record_state_transition(new_state);
         self.update_animation(new_state.as_str().into());
 
         self.previous_state = self.current_state;
@@ -405,6 +487,72 @@ impl Player {
         self.current_state.enter_state(self);
     }
 
+    /// Sets the input event that triggered the transition the player is about to make
+    /// Transitions that do not set a trigger are recorded with the event last fetched from the
+    /// player's input manager during the same physics frame instead
+    ///
+    /// # Arguments
+    /// * `event` - The event that triggered the transition
+    pub fn set_transition_trigger(&mut self, event: Option<PlayerEvents>) {
+        self.transition_trigger = event;
+    }
+
+    /// Records a state transition in the player's state history
+    /// Only the most recent STATE_HISTORY_LENGTH transitions are kept
+    /// While debug mode is on every transition is also printed
+    ///
+    /// # Arguments
+    /// * `new_state` - The state the player is entering
+    fn record_state_transition(&mut self, new_state: PlayerStates) {
+        let fetched_event = self
+            .get_input_manager()
+            .bind_mut()
+            .take_last_fetched_event();
+
+        let record = StateRecord {
+            frame: Engine::singleton().get_physics_frames(),
+            from: self.current_state,
+            to: new_state,
+            event: self.transition_trigger.take().or(fetched_event),
+            velocity: self.base().get_velocity(),
+        };
+
+        if self.settings.bind().is_debug_mode() {
+            godot_print!("Player {} state: {}", self.player_id, record);
+        }
+
+        self.state_history.push_back(record);
+        while self.state_history.len() > STATE_HISTORY_LENGTH {
+            self.state_history.pop_front();
+        }
+    }
+
+    /// Gets the most recent state transitions of the player for the debug panel
+    /// Each transition has the keys frame, from, to, event and velocity
+    ///
+    /// # Returns
+    /// * `Array<Dictionary>` - The state history, oldest first
+    #[func]
+    pub fn get_state_history(&self) -> Array<Dictionary> {
+        self.state_history
+            .iter()
+            .map(|record| record.to_dictionary())
+            .collect()
+    }
+
+    /// Prints the player's state history to the output
+    #[func]
+    pub fn dump_state_history(&self) {
+        godot_print!(
+            "Player {} state history ({} transitions):",
+            self.player_id,
+            self.state_history.len()
+        );
+        for record in self.state_history.iter() {
+            godot_print!("  {}", record);
+        }
+    }
+
     /// Set the delta time of the player
     ///
     /// # Arguments
@@ -430,6 +578,86 @@ impl Player {
         self.health
     }
 
+    #[func]
+    /// Get the stamina of the player
+    ///
+    /// # Returns
+    /// * `f64` - The stamina of the player
+    pub fn get_stamina(&self) -> f64 {
+        self.stamina
+    }
+
+    /// Checks if the player has enough stamina for an action
+    /// Costs are scaled by the fatigue scale so burning pewter allows actions without stamina
+    ///
+    /// # Arguments
+    /// * `cost` - The stamina cost of the action
+    ///
+    /// # Returns
+    /// * `bool` - True if the player can afford the action, false otherwise
+    pub fn has_stamina(&self, cost: f64) -> bool {
+        self.stamina >= cost * self.fatigue_scale
+    }
+
+    /// Checks if the player is too tired to sprint
+    /// The player is never fatigued while pewter is suppressing their fatigue
+    ///
+    /// # Returns
+    /// * `bool` - True if the player is out of stamina, false otherwise
+    pub fn is_fatigued(&self) -> bool {
+        self.stamina <= 0.0 && self.fatigue_scale > 0.0
+    }
+
+    /// Drains the player's stamina by the given cost scaled by the fatigue scale
+    ///
+    /// # Arguments
+    /// * `cost` - The stamina cost of the action
+    pub fn drain_stamina(&mut self, cost: f64) {
+        self.stamina = (self.stamina - cost * self.fatigue_scale).clamp(0.0, MAX_STAMINA);
+    }
+
+    /// Set the multiplier applied to stamina costs
+    /// A scale of 0 means the player does not tire at all
+    ///
+    /// # Arguments
+    /// * `fatigue_scale` - The multiplier applied to stamina costs
+    pub fn set_fatigue_scale(&mut self, fatigue_scale: f64) {
+        self.fatigue_scale = fatigue_scale;
+    }
+
+    /// Regenerates the player's stamina while they are not exerting themselves
+    /// No stamina is regained while the player is suffering from pewter drag
+    fn regenerate_stamina(&mut self) {
+        if self.is_exerting() || self.is_timeout_event_active(TimeoutEvents::PewterDrag) {
+            return;
+        }
+
+        self.stamina = (self.stamina + STAMINA_REGEN_RATE * self.delta).min(MAX_STAMINA);
+    }
+
+    /// Checks if the player is in a state that uses up stamina
+    ///
+    /// # Returns
+    /// * `bool` - True if the player is sprinting, rolling or sliding, false otherwise
+    pub fn is_exerting(&self) -> bool {
+        matches!(
+            self.current_state,
+            PlayerStates::Sprint
+                | PlayerStates::Roll
+                | PlayerStates::Slide
+                | PlayerStates::SlideCrouch
+        )
+    }
+
+    /// Collapses the player into pewter drag
+    /// This happens when the player runs out of pewter while exerting themselves.
+    /// The player is left without stamina and is slowed until the drag wears off.
+    pub fn pewter_crash(&mut self) {
+        self.stamina = 0.0;
+        self.add_timeout_event(TimeoutEvents::PewterDrag);
+        self.set_state(PlayerStates::CrouchStart);
+    }
+
     #[func]
     /// Get the eliminations of the player
     ///
@@ -461,25 +689,22 @@ impl Player {
         }
     }
 
+    /// Get the maximum health of the player
+    ///
+    /// # Returns
+    /// * `f64` - The maximum health of the player
+    pub fn get_max_health(&self) -> f64 {
+        MAX_HEALTH
+    }
+
     #[func]
     /// Adjust the health of the player
     /// Health is clamped between MIN_HEALTH and MAX_HEALTH
+    /// Damage should go through take_damage so that modifiers and eliminations are handled
     ///
     /// # Arguments
     /// * `adjustment` - The amount to adjust the health by
-    pub fn adjust_health(&mut self, mut adjustment: f64) {
-        if adjustment.signum() == -1.0 && self.is_burning_metal(MetalType::Pewter) {
-            if adjustment <= MAX_HEALTH * 0.05 {
-                adjustment = 0.0;
-            } else if adjustment <= MAX_HEALTH * 0.25 {
-                adjustment = adjustment * 0.5;
-            } else if adjustment <= MAX_HEALTH * 0.50 {
-                adjustment = adjustment * 0.7;
-            } else {
-                adjustment = adjustment * 0.9;
-            }
-        }
-
+    pub fn adjust_health(&mut self, adjustment: f64) {
         // Adjust health by the specified amount
         self.health += adjustment;
 
@@ -490,18 +715,294 @@ impl Player {
         self.get_health_bar().set_value(self.get_health());
     }
 
+    /// Increments the number of eliminations this player got this round
+    /// The game's total is credited separately by the DamageResolver
+    pub fn increment_eliminations(&mut self) {
+        self.eliminations += 1;
+    }
+
+    /// Deals damage to the player
+    /// The damage is passed through the DamageResolver which applies modifiers, knockback and
+    /// credits the elimination if the player is killed
+    ///
+    /// # Arguments
+    /// * `event` - The damage being dealt to the player
+    ///
+    /// # Returns
+    /// * `f64` - The amount of damage that was actually dealt
+    pub fn take_damage(&mut self, event: DamageEvent) -> f64 {
+        DamageResolver::resolve(self, event)
+    }
+
+    /// Records damage from another player in the player's damage history
+    /// Only the most recent DAMAGE_HISTORY_LENGTH records are kept
+    ///
+    /// # Arguments
+    /// * `source` - The player that dealt the damage
+    /// * `source_id` - The id of the player that dealt the damage
+    /// * `damage_type` - How the damage was dealt
+    /// * `attack` - The melee attack that dealt the damage, if any
+    /// * `amount` - The amount of damage dealt
+    pub fn record_damage_source(
+        &mut self,
+        source: Gd<Player>,
+        source_id: i32,
+        damage_type: DamageType,
+        attack: Option<AttackKind>,
+        amount: f64,
+    ) {
+        self.damage_history.push_back(DamageRecord {
+            source,
+            source_id,
+            damage_type,
+            attack,
+            amount,
+            time: Instant::now(),
+        });
+
+        while self.damage_history.len() > DAMAGE_HISTORY_LENGTH {
+            self.damage_history.pop_front();
+        }
+    }
+
+    /// Records that another player sent something into this player without damaging them
+    /// This lets the other player be credited if this player dies to the environment shortly after
+    /// The other player is also made the source of any fall, impact or environmental damage this
+    /// player takes within the pusher memory
+    ///
+    /// # Arguments
+    /// * `source` - The player responsible for the contact
+    /// * `damage_type` - How the contact happened
+    pub fn tag_by(&mut self, source: Gd<Player>, damage_type: DamageType) {
+        if source.instance_id() == self.base().instance_id() {
+            return;
+        }
+
+        self.last_pusher = Some((source.clone(), Instant::now()));
+        let source_id = source.bind().get_player_id();
+        self.record_damage_source(source, source_id, damage_type, None, 0.0);
+    }
+
+    /// Gets the player that recently sent something into this player
+    ///
+    /// # Returns
+    /// * `Option<Gd<Player>>` - The player if they did it within the pusher memory and are still in
+    ///   the game
+    pub fn get_last_pusher(&self) -> Option<Gd<Player>> {
+        match self.last_pusher.as_ref() {
+            Some((pusher, time))
+                if time.elapsed() <= PUSHER_MEMORY && pusher.is_instance_valid() =>
+            {
+                Some(pusher.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Stuns the player after they are hit, giving them invulnerability frames and putting them
+    /// in the Hurt state from whatever state they were in
+    ///
+    /// # Arguments
+    /// * `stun` - How long the player is stunned for
+    pub fn hurt(&mut self, stun: Duration) {
+        self.add_timeout_event(TimeoutEvents::Invulnerable);
+        self.stun(stun);
+    }
+
+    /// Stuns the player without giving them invulnerability frames, such as when their attack
+    /// is parried
+    ///
+    /// # Arguments
+    /// * `stun` - How long the player is stunned for
+    pub fn stun(&mut self, stun: Duration) {
+        self.add_timeout_event_for(TimeoutEvents::HitStun, stun);
+        self.set_state(PlayerStates::Hurt);
+    }
+
+    /// Checks if hitting something at a speed is hard enough to hurt the player
+    /// Players burning pewter shrug off any impact
+    ///
+    /// # Arguments
+    /// * `speed` - The speed the player hit something at
+    ///
+    /// # Returns
+    /// * `bool` - True if the impact hurts the player
+    pub fn is_hard_impact(&mut self, speed: f32) -> bool {
+        speed.abs() > HARD_IMPACT_SPEED && !self.is_burning_metal(MetalType::Pewter)
+    }
+
+    /// Deals damage to the player for hitting the ground or a wall too fast
+    /// The damage grows with how much faster than the hard impact speed the player was going
+    /// The player that last sent something into this player is held responsible for the damage
+    ///
+    /// # Arguments
+    /// * `speed` - The speed the player hit something at
+    /// * `damage_type` - How the player hit something
+    ///
+    /// # Returns
+    /// * `f64` - The amount of damage that was actually dealt
+    pub fn take_impact_damage(&mut self, speed: f32, damage_type: DamageType) -> f64 {
+        if !self.is_hard_impact(speed) {
+            return 0.0;
+        }
+
+        let amount = (speed.abs() - HARD_IMPACT_SPEED) as f64 * IMPACT_DAMAGE_PER_SPEED;
+        let source = self.get_last_pusher();
+        self.take_damage(DamageEvent::new(source, damage_type, amount, Vector2::ZERO))
+    }
+
+    /// Hurts the player if they were pushed or pulled into a wall by steel or iron too fast
+    /// A slam that hurts the player makes them stumble
+    fn check_wall_slam(&mut self) {
+        if !self.base().is_on_wall() || !self.is_timeout_event_active(TimeoutEvents::MetalPropelled)
+        {
+            return;
+        }
+
+        // Only the first impact with the wall hurts
+        self.timeout_events.remove(&TimeoutEvents::MetalPropelled);
+        let speed = self.previous_velocity.x;
+        if self.take_impact_damage(speed, DamageType::Impact) > 0.0 {
+            self.set_state(PlayerStates::Stumble);
+        }
+    }
+
+    /// Gets the velocity of the player before they last moved
+    /// This is the velocity the player hit the ground or a wall with
+    ///
+    /// # Returns
+    /// * `Vector2` - The velocity of the player before they last moved
+    pub fn get_previous_velocity(&self) -> Vector2 {
+        self.previous_velocity
+    }
+
+    /// Checks if the player is guarding against melee attacks
+    ///
+    /// # Returns
+    /// * `bool` - True if the player is in the Block state
+    pub fn is_blocking(&self) -> bool {
+        self.current_state == PlayerStates::Block
+    }
+
+    /// Checks if the player just started blocking and will parry anything that hits them
+    ///
+    /// # Returns
+    /// * `bool` - True if the player's parry window is open
+    pub fn is_parrying(&self) -> bool {
+        self.is_blocking() && self.is_timeout_event_active(TimeoutEvents::ParryWindow)
+    }
+
+    /// Checks if the player was hit recently enough that they can not be damaged again
+    ///
+    /// # Returns
+    /// * `bool` - True if the player is invulnerable
+    pub fn is_invulnerable(&self) -> bool {
+        self.is_timeout_event_active(TimeoutEvents::Invulnerable)
+    }
+
+    /// Gets the most recent damage the player took from other players
+    ///
+    /// # Returns
+    /// * `&VecDeque<DamageRecord>` - The damage history, oldest first
+    pub fn get_damage_history(&self) -> &VecDeque<DamageRecord> {
+        &self.damage_history
+    }
+
     #[func]
-    /// Adjust the eliminations of the player
+    /// Deals melee damage to the player from an attacking player
+    /// The damage and knockback come from the attack the attacker is doing, and the player is
+    /// knocked away from the attacker
     ///
     /// # Arguments
-    /// * `attacker_id` - The id of the player who eliminated this player
-    pub fn increment_eliminations(&mut self, attacker_id: i32) {
-        self.eliminations += 1;
-        // update the eliminations counter for a player in game
-        self.base()
-            .get_node_as::<Game>("/root/Game")
-            .bind_mut()
-            .update_eliminations(attacker_id);
+    /// * `attacker` - The player that hit this player
+    pub fn receive_melee_hit(&mut self, attacker: Gd<Player>) {
+        let direction = if attacker.get_global_position().x > self.base().get_global_position().x {
+            -1.0
+        } else {
+            1.0
+        };
+
+        let attack = attacker
+            .bind()
+            .get_current_attack()
+            .unwrap_or(AttackKind::Jab);
+        let data = AttackData::for_kind(attack);
+
+        let knockback = Vector2::new(direction * data.knockback.x, data.knockback.y);
+        self.take_damage(
+            DamageEvent::new(Some(attacker), DamageType::Melee, data.damage, knockback)
+                .with_attack(attack),
+        );
+    }
+
+    /// Starts a melee attack, restarting the attack animation and lunging the player forward
+    /// The hitbox is turned on and off by the Attack state during the attack's active window
+    /// Starting the next hit of a combo during the current attack's cancel window links the two
+    ///
+    /// # Arguments
+    /// * `attack` - The attack to start
+    pub fn start_attack(&mut self, attack: AttackKind) {
+        let data = AttackData::for_kind(attack);
+
+        let elapsed = self.get_attack_elapsed();
+        self.combo_link = self.get_current_attack().filter(|previous| {
+            let previous_data = AttackData::for_kind(*previous);
+            previous_data.next == Some(attack)
+                && elapsed >= previous_data.cancel_start
+                && elapsed <= previous_data.duration
+        });
+
+        self.disable_hitbox();
+        self.current_attack = Some((attack, Instant::now()));
+        self.update_animation(data.animation.into());
+
+        if data.lunge != Vector2::ZERO {
+            let direction = self.direction.signum();
+            self.add_force(Force::Lunge {
+                impulse: Vector2::new(data.lunge.x * direction, data.lunge.y),
+            });
+        }
+    }
+
+    /// Ends the player's current melee attack and turns off their hitbox
+    pub fn end_attack(&mut self) {
+        self.disable_hitbox();
+        self.current_attack = None;
+        self.combo_link = None;
+    }
+
+    /// Gets the melee attack the player is doing
+    ///
+    /// # Returns
+    /// * `Option<AttackKind>` - The attack, if the player is attacking
+    pub fn get_current_attack(&self) -> Option<AttackKind> {
+        self.current_attack.map(|(attack, _)| attack)
+    }
+
+    /// Gets the attack the player's current attack was chained from in a combo
+    ///
+    /// # Returns
+    /// * `Option<AttackKind>` - The previous hit of the combo, if the current attack continues one
+    pub fn get_combo_link(&self) -> Option<AttackKind> {
+        self.combo_link
+    }
+
+    /// Gets how long the player has been doing their current melee attack
+    ///
+    /// # Returns
+    /// * `Duration` - The time since the attack started, zero if the player is not attacking
+    pub fn get_attack_elapsed(&self) -> Duration {
+        self.current_attack
+            .map(|(_, start)| start.elapsed())
+            .unwrap_or(Duration::ZERO)
+    }
+
+    /// Checks if the player's hitbox is currently on
+    ///
+    /// # Returns
+    /// * `bool` - True if the hitbox is on
+    pub fn is_attacking(&self) -> bool {
+        self.is_attacking
     }
 
     /// Adjusts the coins in this players coin_counter positively or negatively.
@@ -547,9 +1048,7 @@ impl Player {
     /// # Returns
     /// * `f32` - The direction the player is trying to move as well as the magnitude of the movement
     pub fn get_horizontal_movement(&mut self) -> f32 {
-        let move_left = StringName::from(format!("move_left{}", self.device_id));
-        let move_right = StringName::from(format!("move_right{}", self.device_id));
-        Input::singleton().get_axis(&move_left, &move_right)
+        self.get_input_manager().bind().get_horizontal_movement()
     }
 
     /// Sets the player's velocity to the speed passed * the magnitude of the direction passed
@@ -744,7 +1243,16 @@ impl Player {
     /// # Arguments
     /// * `event` - The event to add
     pub fn add_timeout_event(&mut self, event: TimeoutEvents) {
-        let duration = event.get_duration();
+        self.add_timeout_event_for(event, event.get_duration());
+    }
+
+    /// Add a timeout event to the player that lasts for a specific duration
+    /// This is used for events such as hit-stun where the duration depends on what caused it
+    ///
+    /// # Arguments
+    /// * `event` - The event to add
+    /// * `duration` - How long the event lasts
+    pub fn add_timeout_event_for(&mut self, event: TimeoutEvents, duration: Duration) {
         self.timeout_events
             .insert(event, (Instant::now(), duration));
     }
@@ -768,6 +1276,115 @@ impl Player {
         false
     }
 
+    /// Check if the player is against a wall and holding towards it in the air
+    ///
+    /// # Returns
+    /// * `bool` - True if the player should slide down the wall
+    pub fn is_pushing_into_wall(&mut self) -> bool {
+        if !self.base().is_on_wall() || self.base().is_on_floor() {
+            return false;
+        }
+
+        let horizontal_dir = self.get_horizontal_movement();
+        let wall_normal = self.base().get_wall_normal().x;
+        horizontal_dir != 0.0 && horizontal_dir.signum() == -wall_normal.signum()
+    }
+
+    /// Remembers the wall the player is sliding down so they can jump off of it even after
+    /// sliding off of it
+    pub fn update_wall_direction(&mut self) {
+        if self.base().is_on_wall() {
+            self.wall_direction = self.base().get_wall_normal().x.signum();
+        }
+    }
+
+    /// Sets the direction pointing away from the wall the player is against
+    ///
+    /// # Arguments
+    /// * `wall_direction` - 1.0 if the wall faces right, -1.0 if it faces left
+    pub fn set_wall_direction(&mut self, wall_direction: f32) {
+        self.wall_direction = wall_direction.signum();
+    }
+
+    /// Check if there is a ledge in front of the player that they can grab
+    /// A ledge is found when the wall ray hits a tile but the head ray just above it does not.
+    /// The top corner of the ledge is then found by casting down in front of the player.
+    ///
+    /// # Returns
+    /// * `bool` - True if the player can grab a ledge
+    pub fn detect_ledge(&mut self) -> bool {
+        if self.is_timeout_event_active(TimeoutEvents::LedgeRegrab) {
+            return false;
+        }
+
+        let reach = Vector2::new(self.direction * LEDGE_REACH, 0.0);
+        let mut wall_ray = self.get_ledge_wall_ray();
+        let mut head_ray = self.get_ledge_head_ray();
+        wall_ray.set_target_position(reach);
+        head_ray.set_target_position(reach);
+        wall_ray.force_raycast_update();
+        head_ray.force_raycast_update();
+
+        if !wall_ray.is_colliding() || head_ray.is_colliding() {
+            return false;
+        }
+
+        let Some(mut space_state) = self
+            .base()
+            .get_world_2d()
+            .and_then(|world| world.get_direct_space_state())
+        else {
+            return false;
+        };
+
+        // Cast down just inside the wall, from the head ray to the wall ray, to find the top
+        let ledge_x = wall_ray.get_collision_point().x + self.direction;
+        let Some(mut query) = PhysicsRayQueryParameters2D::create(
+            Vector2::new(ledge_x, head_ray.get_global_position().y),
+            Vector2::new(ledge_x, wall_ray.get_global_position().y + 1.0),
+        ) else {
+            return false;
+        };
+
+        query.set_collision_mask(TILE_COLLISION_LAYER);
+
+        let result = space_state.intersect_ray(&query);
+        match result.get("position") {
+            Some(position) => {
+                self.ledge_point = position.to::<Vector2>();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Get the global position of the top corner of the ledge the player last grabbed
+    ///
+    /// # Returns
+    /// * `Vector2` - The top corner of the ledge
+    pub fn get_ledge_point(&self) -> Vector2 {
+        self.ledge_point
+    }
+
+    /// Get the direction pointing away from the wall the player last slid down
+    ///
+    /// # Returns
+    /// * `f32` - 1.0 if the wall faces right, -1.0 if it faces left
+    pub fn get_wall_direction(&self) -> f32 {
+        self.wall_direction
+    }
+
+    /// Check if a timeout event is currently active
+    ///
+    /// # Arguments
+    /// * `event` - The event to check for
+    ///
+    /// # Returns
+    /// * `bool` - True if the event has not timed out yet, false otherwise
+    pub fn is_timeout_event_active(&self, event: TimeoutEvents) -> bool {
+        self.timeout_events.contains_key(&event)
+    }
+
     /// Check if any timeout events have expired and remove them from the timeout_events HashMap
     fn expire_timeout_events(&mut self) {
         self.timeout_events.retain(|_event, time_tuple| {
@@ -815,12 +1432,19 @@ impl Player {
     /// Reset the player to their default values
     /// This method resets the speed scale of the player's sprite to 1.0
     /// It also resets the run and jump force of the player to their default values
+    /// which are lowered while the player is suffering from pewter drag
     fn reset_player(&mut self) {
         let mut sprite: Gd<AnimatedSprite2D> = self.get_sprite();
         sprite.set_speed_scale(1.0);
-        self.set_run_speed(DEFAULT_RUN_SPEED);
-        self.set_jump_force(DEFAULT_JUMP_FORCE);
         self.set_gravity(self.default_gravity);
+
+        if self.is_timeout_event_active(TimeoutEvents::PewterDrag) {
+            self.set_run_speed(DEFAULT_RUN_SPEED * PEWTER_DRAG_RUN_SCALE);
+            self.set_jump_force(DEFAULT_JUMP_FORCE * PEWTER_DRAG_JUMP_SCALE);
+        } else {
+            self.set_run_speed(DEFAULT_RUN_SPEED);
+            self.set_jump_force(DEFAULT_JUMP_FORCE);
+        }
     }
 
     /// Adds a force to the player's forces queue
@@ -971,6 +1595,10 @@ impl Player {
             } => {
                 base_velocity.x = x_acceleration;
                 base_velocity.y = y_acceleration;
+                self.add_timeout_event(TimeoutEvents::MetalPropelled);
+            }
+            Force::Knockback { impulse } | Force::Lunge { impulse } => {
+                base_velocity += impulse;
             }
             _ => {}
         }
@@ -1053,6 +1681,70 @@ impl Player {
         }
     }
 
+    /// Checks if the player can sense a metal object with steel or iron
+    /// The metal object has to be tracked by the player, within range and, if line of sight is
+    /// enabled, not hidden behind a wall.
+    ///
+    /// # Arguments
+    /// * `metal_object` - The metal object to check
+    /// * `range` - The distance in pixels the metal object can be sensed from
+    ///
+    /// # Returns
+    /// * `bool` - True if the player can sense the metal object, false otherwise
+    pub fn can_sense_metal_object(&self, metal_object: &Gd<MetalObject>, range: f32) -> bool {
+        if !self.is_metal_object_in_range(metal_object) {
+            return false;
+        }
+
+        let distance = self
+            .base()
+            .get_global_position()
+            .distance_to(metal_object.get_global_position());
+        if distance > range {
+            return false;
+        }
+
+        !self.settings.bind().is_line_of_sight_enabled() || self.has_line_of_sight(metal_object)
+    }
+
+    /// Checks if there is a wall between the player and a metal object
+    /// The ray only collides with the tile layer, so any hit is a wall and players and other metal
+    /// objects do not hide metal objects behind them.
+    ///
+    /// # Arguments
+    /// * `metal_object` - The metal object to check
+    ///
+    /// # Returns
+    /// * `bool` - True if the player can see the metal object, false otherwise
+    fn has_line_of_sight(&self, metal_object: &Gd<MetalObject>) -> bool {
+        let Some(mut space_state) = self
+            .base()
+            .get_world_2d()
+            .and_then(|world| world.get_direct_space_state())
+        else {
+            return true;
+        };
+
+        let Some(mut query) = PhysicsRayQueryParameters2D::create(
+            self.base().get_global_position(),
+            metal_object.get_global_position(),
+        ) else {
+            return true;
+        };
+
+        query.set_collision_mask(TILE_COLLISION_LAYER);
+
+        space_state.intersect_ray(&query).is_empty()
+    }
+
+    /// Gets the settings for the game
+    ///
+    /// # Returns
+    /// * `Gd<Settings>` - The settings singleton
+    pub fn get_settings(&self) -> Gd<Settings> {
+        self.settings.clone()
+    }
+
     #[func]
     /// Adds a player to the player's vec of nearby players
     ///
@@ -1083,7 +1775,8 @@ impl Player {
         &mut self.nearby_players
     }
 
-    /// Reveals the particles of the player if the player is not burning copper
+    /// Reveals the particles of the player
+    /// Coppercloud concealment is checked by the bronze burner before this is called.
     ///
     /// # Arguments
     /// * `visibility_layer` - The visibility layer to set for the particles
@@ -1093,11 +1786,7 @@ impl Player {
         for metal in MetalType::iter() {
             let mut particles = self.get_metal_particles(metal);
             if particles.is_visible_in_tree() {
-                if !self.is_burning_metal(MetalType::Copper) {
-                    particles.set_visibility_layer(current_layer | visibility_layer);
-                } else {
-                    particles.set_visibility_layer(current_layer & !visibility_layer);
-                }
+                particles.set_visibility_layer(current_layer | visibility_layer);
             }
         }
     }
@@ -1139,11 +1828,6 @@ impl Player {
     pub fn add_active_metal(&mut self, metal: MetalType) {
         if !self.active_metals.contains(&metal) {
             self.active_metals.push(metal);
-            if metal == MetalType::Copper {
-                // get the visibility mask of the current player
-                let visibility_mask = 1 << self.player_id * 2;
-                self.hide_particles(visibility_mask);
-            }
         }
     }
 
@@ -1157,6 +1841,14 @@ impl Player {
         }
     }
 
+    /// Gets the metals the player is currently burning
+    ///
+    /// # Returns
+    /// * `&Vec<MetalType>` - The metals the player is burning
+    pub fn get_active_metals(&self) -> &Vec<MetalType> {
+        &self.active_metals
+    }
+
     /// Checks if the player is burning a specific metal
     ///
     /// # Arguments
@@ -1203,6 +1895,7 @@ impl Player {
     }
 
     /// Enable the hitbox of the player when they are attacking
+    /// The hitbox is shaped to match the player's current attack
     ///
     /// # Arguments
     /// * `owner` - A reference to the node for the hitbox of the player
@@ -1213,6 +1906,11 @@ impl Player {
         let mut right_hitbox = self.base().get_node_as::<Area2D>("RightHitbox");
         let mut left_hitbox = self.base().get_node_as::<Area2D>("LeftHitbox");
 
+        let attack = self.get_current_attack().unwrap_or(AttackKind::Jab);
+        let data = AttackData::for_kind(attack);
+        Self::shape_hitbox(&right_hitbox, &data, 1.0);
+        Self::shape_hitbox(&left_hitbox, &data, -1.0);
+
         // Enable the hitbox of the player depending on the direction they are facing
         if self.direction > 0.0 {
             right_hitbox.set_monitoring(true);
@@ -1227,6 +1925,24 @@ impl Player {
         }
     }
 
+    /// Resizes and moves a hitbox to match an attack
+    ///
+    /// # Arguments
+    /// * `hitbox` - The hitbox to shape
+    /// * `data` - The data of the attack
+    /// * `side` - 1 for the right hitbox, -1 for the left hitbox
+    fn shape_hitbox(hitbox: &Gd<Area2D>, data: &AttackData, side: f32) {
+        let mut shape_node = hitbox.get_node_as::<CollisionShape2D>("HitboxShape");
+        shape_node.set_position(Vector2::new(
+            data.hitbox_offset.x * side,
+            data.hitbox_offset.y,
+        ));
+
+        let mut shape = RectangleShape2D::new_gd();
+        shape.set_size(data.hitbox_size);
+        shape_node.set_shape(&shape);
+    }
+
     /// Disable the hitbox of the player when they are not attacking
     ///
     /// # Arguments
@@ -1261,6 +1977,15 @@ impl Player {
     #[signal]
     fn id_changed();
 
+    /// Emitted whenever the player takes damage so the HUD and audio can react
+    ///
+    /// # Arguments
+    /// * `amount` - The amount of damage dealt after modifiers
+    /// * `damage_type` - How the damage was dealt
+    /// * `source_id` - The id of the player who dealt the damage or -1 if there was none
+    #[signal]
+    pub fn damage_taken(amount: f64, damage_type: GString, source_id: i32);
+
     /// A signal that is emitted by the player when it is using tin
     #[signal]
     pub fn tin_activated(light_level: f32, transition_time: f64);
@@ -1444,6 +2169,46 @@ impl Player {
         self.get_cached_node(CachedNode::CopperParticles, "CopperParticles")
     }
 
+    /// Getter for the CopperCloud node
+    /// This effectively caches the CopperCloud node so that it does not have to be found every
+    /// time it is needed.
+    ///
+    /// # Returns
+    /// * `CopperCloud` - The CopperCloud node
+    pub fn get_copper_cloud(&mut self) -> Gd<CopperCloud> {
+        self.get_cached_node(CachedNode::CopperCloud, "CopperCloud")
+    }
+
+    /// Getter for the BronzeRadar node
+    /// This effectively caches the BronzeRadar node so that it does not have to be found every
+    /// time it is needed.
+    ///
+    /// # Returns
+    /// * `BronzeRadar` - The BronzeRadar node
+    pub fn get_bronze_radar(&mut self) -> Gd<BronzeRadar> {
+        self.get_cached_node(CachedNode::BronzeRadar, "BronzeRadar")
+    }
+
+    /// Getter for the LedgeWallRay node
+    /// This effectively caches the LedgeWallRay node so that it does not have to be found every
+    /// time it is needed.
+    ///
+    /// # Returns
+    /// * `RayCast2D` - The ray that checks for a wall in front of the player's upper body
+    fn get_ledge_wall_ray(&mut self) -> Gd<RayCast2D> {
+        self.get_cached_node(CachedNode::LedgeWallRay, "LedgeWallRay")
+    }
+
+    /// Getter for the LedgeHeadRay node
+    /// This effectively caches the LedgeHeadRay node so that it does not have to be found every
+    /// time it is needed.
+    ///
+    /// # Returns
+    /// * `RayCast2D` - The ray that checks for open space in front of the player's head
+    fn get_ledge_head_ray(&mut self) -> Gd<RayCast2D> {
+        self.get_cached_node(CachedNode::LedgeHeadRay, "LedgeHeadRay")
+    }
+
     /// Getter for the Disconnected node
     /// This effectively caches the Disconnected node so that it does not have to be found every
     /// time it is needed.
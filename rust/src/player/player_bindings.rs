@@ -0,0 +1,260 @@
+//! player_bindings.rs
+//!
+//! This file contains the bindings one player has changed from the global input map. Rebinding an
+//! action for a player only changes that player's bindings, so two players sharing an action name
+//! can bind it to different buttons. Actions a player has not rebound keep using the input map.
+//! The bindings are saved to their own file under the player's binding profile, apart from the
+//! settings file the ConfigFileHandler manages.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use std::collections::HashMap;
+
+use godot::classes::{ConfigFile, InputMap};
+use godot::global::Error;
+use godot::prelude::*;
+
+use super::enums::input_action::InputAction;
+use super::enums::input_binding::InputBinding;
+use super::keyboard_profile::KeyboardProfile;
+
+/// The file the bindings are saved in. The bindings are kept out of the settings file so the
+/// ConfigFileHandler saving its copy of the settings does not overwrite them
+const BINDINGS_FILE_PATH: &str = "user://bindings.ini";
+/// The start of the name of the section each profile's bindings are saved in
+const SECTION_PREFIX: &str = "bindings_";
+
+/// The actions one player has rebound.
+#[derive(Debug, Default, Clone)]
+pub struct PlayerBindings {
+    /// The bindings of every action the player has rebound. An action with no bindings has been
+    /// unbound by the player.
+    overrides: HashMap<String, Vec<InputBinding>>,
+}
+
+impl PlayerBindings {
+    /// Loads a profile's bindings from the bindings file.
+    /// A profile that has never been saved has no rebound actions.
+    ///
+    /// # Arguments
+    /// * `profile` - The name of the binding profile.
+    ///
+    /// # Returns
+    /// * `PlayerBindings` - The bindings saved for the profile.
+    pub fn load(profile: &str) -> PlayerBindings {
+        let mut bindings = PlayerBindings::default();
+        let mut config = ConfigFile::new_gd();
+        let section = Self::section(profile);
+
+        if config.load(BINDINGS_FILE_PATH) != Error::OK || !config.has_section(&section) {
+            return bindings;
+        }
+
+        for action in config.get_section_keys(&section).as_slice() {
+            let saved = config
+                .get_value(&section, action)
+                .try_to::<VariantArray>()
+                .unwrap_or_default();
+
+            let action_bindings = saved
+                .iter_shared()
+                .filter_map(|binding| binding.try_to::<Dictionary>().ok())
+                .filter_map(|binding| InputBinding::from_dictionary(&binding))
+                .collect();
+            bindings
+                .overrides
+                .insert(action.to_string(), action_bindings);
+        }
+
+        bindings
+    }
+
+    /// Saves the bindings to the bindings file under a profile, replacing what was saved for the
+    /// profile before. The other profiles in the file are left as they are.
+    ///
+    /// # Arguments
+    /// * `profile` - The name of the binding profile.
+    pub fn save(&self, profile: &str) {
+        let mut config = ConfigFile::new_gd();
+        let section = Self::section(profile);
+
+        // A missing bindings file is created by saving
+        let _ = config.load(BINDINGS_FILE_PATH);
+        if config.has_section(&section) {
+            config.erase_section(&section);
+        }
+
+        for (action, bindings) in self.overrides.iter() {
+            let saved: VariantArray = bindings
+                .iter()
+                .map(|binding| binding.to_dictionary().to_variant())
+                .collect();
+            config.set_value(&section, action.as_str(), &saved.to_variant());
+        }
+
+        if config.save(BINDINGS_FILE_PATH) != Error::OK {
+            godot_error!("Failed to save the {} bindings", profile);
+        }
+    }
+
+    /// Builds the table a player's input manager uses to find the action a button triggers.
+    /// Rebound actions use the player's bindings and every other action uses the input map's
+    /// events for the player's device. When the input map binds a button to more than one action
+    /// the action listed last takes it.
+    ///
+    /// # Arguments
+    /// * `device_id` - The device of the player.
+    ///
+    /// # Returns
+    /// * `HashMap<InputBinding, InputAction>` - The action each bound button triggers.
+    pub fn lookup_table(&self, device_id: i32) -> HashMap<InputBinding, InputAction> {
+        let mut table = HashMap::new();
+        let input_map_actions: Vec<String> = InputMap::singleton()
+            .get_actions()
+            .iter_shared()
+            .map(|action| action.to_string())
+            .filter(|action| !self.is_rebound(action))
+            .collect();
+
+        // Rebound actions go last so they take their buttons from the input map's actions
+        for name in input_map_actions.iter().chain(self.overrides.keys()) {
+            let Some(action) = InputAction::from_string(name) else {
+                continue;
+            };
+            for binding in self.get(name, device_id) {
+                table.insert(binding, action);
+            }
+        }
+
+        table
+    }
+
+    /// Checks if the player has rebound an action, in which case the input map's events for the
+    /// action are ignored for the player.
+    ///
+    /// # Arguments
+    /// * `action` - The name of the action.
+    ///
+    /// # Returns
+    /// * `bool` - True if the action has been rebound.
+    pub fn is_rebound(&self, action: &str) -> bool {
+        self.overrides.contains_key(action)
+    }
+
+    /// Gets the bindings of an action for a player.
+    /// Actions that have not been rebound use the input map's events for the player's device.
+    ///
+    /// # Arguments
+    /// * `action` - The name of the action.
+    /// * `device_id` - The device of the player.
+    ///
+    /// # Returns
+    /// * `Vec<InputBinding>` - The bindings of the action.
+    pub fn get(&self, action: &str, device_id: i32) -> Vec<InputBinding> {
+        if let Some(bindings) = self.overrides.get(action) {
+            return bindings.clone();
+        }
+
+        let action = StringName::from(action);
+        let mut input_map = InputMap::singleton();
+        if !input_map.has_action(&action) {
+            return Vec::new();
+        }
+
+        input_map
+            .action_get_events(&action)
+            .iter_shared()
+            .filter(|event| {
+                let event_device = KeyboardProfile::event_device(event);
+                event_device == device_id || event_device == -1
+            })
+            .filter_map(|event| InputBinding::from_event(&event))
+            .collect()
+    }
+
+    /// Binds an action to a single binding, replacing its other bindings. The binding is taken
+    /// off any other action it was bound to so one button never triggers two actions.
+    ///
+    /// # Arguments
+    /// * `action` - The name of the action.
+    /// * `binding` - The new binding of the action.
+    /// * `device_id` - The device of the player.
+    pub fn rebind(&mut self, action: &str, binding: InputBinding, device_id: i32) {
+        self.take_binding(binding, device_id);
+        self.overrides.insert(action.to_string(), vec![binding]);
+    }
+
+    /// Moves a binding from whichever action has it to another, leaving the other bindings of
+    /// both actions as they are.
+    ///
+    /// # Arguments
+    /// * `to` - The action the binding is given to.
+    /// * `binding` - The binding to move.
+    /// * `device_id` - The device of the player.
+    pub fn swap(&mut self, to: &str, binding: InputBinding, device_id: i32) {
+        self.take_binding(binding, device_id);
+
+        let mut to_bindings = self.get(to, device_id);
+        to_bindings.push(binding);
+        self.overrides.insert(to.to_string(), to_bindings);
+    }
+
+    /// Removes every binding of an action so the player can not trigger it.
+    ///
+    /// # Arguments
+    /// * `action` - The name of the action.
+    pub fn unbind(&mut self, action: &str) {
+        self.overrides.insert(action.to_string(), Vec::new());
+    }
+
+    /// Puts an action back to using the input map's events.
+    ///
+    /// # Arguments
+    /// * `action` - The name of the action.
+    pub fn reset(&mut self, action: &str) {
+        self.overrides.remove(action);
+    }
+
+    /// Puts every action back to using the input map's events.
+    pub fn reset_all(&mut self) {
+        self.overrides.clear();
+    }
+
+    /// Takes a binding off every action it is bound to.
+    /// Actions that were using the input map are rebound to the rest of their input map events.
+    ///
+    /// # Arguments
+    /// * `binding` - The binding to take off.
+    /// * `device_id` - The device of the player.
+    fn take_binding(&mut self, binding: InputBinding, device_id: i32) {
+        let mut input_map = InputMap::singleton();
+        for action in input_map.get_actions().iter_shared() {
+            let action = action.to_string();
+            if action.starts_with("ui_") {
+                continue;
+            }
+
+            let mut bindings = self.get(&action, device_id);
+            if bindings.contains(&binding) {
+                bindings.retain(|bound| *bound != binding);
+                self.overrides.insert(action, bindings);
+            }
+        }
+
+        // Rebound actions that are not in the input map are only in the overrides
+        for bindings in self.overrides.values_mut() {
+            bindings.retain(|bound| *bound != binding);
+        }
+    }
+
+    /// Gets the name of the section a profile is saved in.
+    ///
+    /// # Arguments
+    /// * `profile` - The name of the binding profile.
+    ///
+    /// # Returns
+    /// * `GString` - The name of the section.
+    fn section(profile: &str) -> GString {
+        format!("{}{}", SECTION_PREFIX, profile).into()
+    }
+}
@@ -1,12 +1,25 @@
 //! attack.rs
 //!
 //! This file contains the implementation of the Attack state for the player.
-//! The Attack state is responsible for handling the player's attack animation
-//! and hitbox.
+//! The Attack state picks which melee attack to do based on the player's situation, turns the
+//! hitbox on during the attack's active window and lets the player chain into the next hit of a
+//! combo or cancel into a jump or roll once the attack's cancel window opens.
+//! The attacks themselves are defined in attack_data.rs.
 //!
 //! Author: Michael Imerman, Charles Barth
 //! Version: Spring 2025
-use crate::player::{player::Player, traits::player_state::PlayerState};
+use godot::obj::WithBaseField;
+
+use crate::player::{
+    attack_data::AttackData,
+    enums::{attack_kind::AttackKind, player_events::PlayerEvents, player_states::PlayerStates},
+    player::Player,
+    player_states::roll::ROLL_STAMINA_COST,
+    traits::player_state::PlayerState,
+};
+
+/// How far the left stick has to be tilted up or down to do a directional attack
+const DIRECTIONAL_ATTACK_THRESHOLD: f32 = 0.5;
 
 /// A struct representing the attack state
 #[derive(Clone, Copy)]
@@ -14,28 +27,120 @@ pub struct Attack;
 
 /// Methods for the Attack struct
 impl PlayerState for Attack {
-    /// The player is now attacking. Enable the hitbox for the melee attack
+    /// The player is now attacking. Start the attack that matches what the player was doing
     ///
     /// # Arguments
     /// * `player` - The player object that is attacking
     fn enter(player: &mut Player) {
-        // Enable the hitbox of the player while they are attacking
-        player.enable_hitbox();
+        let attack = Attack::choose_attack(player);
+        player.start_attack(attack);
     }
 
-    /// On every frame, check if the attack animation is finished.
-    /// If it is, disable the hitbox and set the player's state to the previous state.
+    /// On every frame, turn the hitbox on or off depending on the attack's active window and
+    /// check if the attack is cancelled or finished.
     ///
     /// # Arguments
     /// * `player` - The player object that is attacking
     fn update(player: &mut Player) {
-        if player.is_anim_finished() {
-            // get the previous state of the player
-            let previous_state = player.get_previous_state();
-            // Disable the hitbox of the player when the attack animation is finished
+        let Some(attack) = player.get_current_attack() else {
+            Attack::finish(player);
+            return;
+        };
+
+        let data = AttackData::for_kind(attack);
+        let elapsed = player.get_attack_elapsed();
+
+        let active = elapsed >= data.active_start && elapsed < data.active_end;
+        if active && !player.is_attacking() {
+            player.enable_hitbox();
+        } else if !active && player.is_attacking() {
             player.disable_hitbox();
-            // Set the player's state to the previous state
-            player.set_state(previous_state);
+        }
+
+        // A down air ends as soon as the player hits the ground
+        if attack == AttackKind::DownAir && player.base().is_on_floor() {
+            Attack::finish(player);
+            return;
+        }
+
+        if elapsed >= data.cancel_start {
+            let mut input_manager_unbound = player.get_input_manager();
+            let mut input_manager = input_manager_unbound.bind_mut();
+
+            if let Some(next) = data.next {
+                if input_manager.fetch_player_event(PlayerEvents::Attack) {
+                    drop(input_manager);
+                    player.start_attack(next);
+                    return;
+                }
+            }
+
+            let mut next_state = None;
+            if player.jump_available() && input_manager.fetch_player_event(PlayerEvents::Jump) {
+                next_state = Some(PlayerStates::Jump);
+            } else if player.base().is_on_floor()
+                && player.has_stamina(ROLL_STAMINA_COST)
+                && input_manager.fetch_player_event(PlayerEvents::Roll)
+            {
+                next_state = Some(PlayerStates::Roll);
+            }
+            drop(input_manager);
+
+            if let Some(next_state) = next_state {
+                player.set_state(next_state);
+                return;
+            }
+        }
+
+        if elapsed >= data.duration {
+            Attack::finish(player);
+        }
+    }
+
+    /// However the attack ends the hitbox is turned off and the attack is cleared
+    ///
+    /// # Arguments
+    /// * `player` - The player object that was attacking
+    fn exit(player: &mut Player) {
+        player.end_attack();
+    }
+}
+
+impl Attack {
+    /// Picks the attack the player does when they start attacking.
+    /// Attacking out of a sprint does a dash attack, holding down in the air does a down air and
+    /// holding up does an up attack. Otherwise the player starts the ground combo.
+    ///
+    /// # Arguments
+    /// * `player` - The player object that is attacking
+    ///
+    /// # Returns
+    /// * `AttackKind` - The attack the player does
+    fn choose_attack(player: &mut Player) -> AttackKind {
+        let vertical = player.get_input_manager().bind().get_vertical_movement();
+
+        if player.get_previous_state() == PlayerStates::Sprint {
+            AttackKind::Dash
+        } else if !player.base().is_on_floor() && vertical > DIRECTIONAL_ATTACK_THRESHOLD {
+            AttackKind::DownAir
+        } else if vertical < -DIRECTIONAL_ATTACK_THRESHOLD {
+            AttackKind::Up
+        } else {
+            AttackKind::Jab
+        }
+    }
+
+    /// Returns the player to a state that matches their situation once the attack is over
+    ///
+    /// # Arguments
+    /// * `player` - The player object that is attacking
+    fn finish(player: &mut Player) {
+        if !player.base().is_on_floor() {
+            player.set_state(PlayerStates::Fall);
+        } else if player.get_horizontal_movement() != 0.0 {
+            player.set_state(PlayerStates::Run);
+        } else {
+            player.set_state(PlayerStates::Idle);
         }
     }
 }
@@ -0,0 +1,52 @@
+//! block.rs
+//!
+//! This file contains the implementation of the Block state for the player.
+//! The Block state is held while the block button is held. Melee hits against a blocking player
+//! are reduced, and hits landing right after the block starts are parried. A player whose guard
+//! is broken can not block again until the GuardBroken timeout ends.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use crate::player::{
+    enums::{force::Force, timeout_events::TimeoutEvents},
+    player::Player,
+    traits::player_state::PlayerState,
+};
+
+/// A struct representing the block state
+#[derive(Clone, Copy)]
+pub struct Block;
+
+impl PlayerState for Block {
+    /// Opens the parry window unless the player has just tried to parry
+    ///
+    /// # Arguments
+    /// * `player` - The player that is blocking
+    fn enter(player: &mut Player) {
+        if !player.is_timeout_event_active(TimeoutEvents::ParryCooldown) {
+            player.add_timeout_event(TimeoutEvents::ParryWindow);
+            player.add_timeout_event(TimeoutEvents::ParryCooldown);
+        }
+    }
+
+    /// The player holds their ground until they let go of block, run out of stamina or leave
+    /// the ground
+    ///
+    /// # Arguments
+    /// * `player` - The player that is blocking
+    fn update(player: &mut Player) {
+        player.add_force(Force::Stop {
+            horizontal: true,
+            vertical: false,
+        });
+    }
+
+    /// A player that is out of stamina or recovering from a broken guard can not raise their
+    /// guard
+    ///
+    /// # Arguments
+    /// * `player` - The player trying to block
+    fn can_enter(player: &Player) -> bool {
+        !player.is_fatigued() && !player.is_timeout_event_active(TimeoutEvents::GuardBroken)
+    }
+}
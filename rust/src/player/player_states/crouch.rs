@@ -6,12 +6,7 @@
 //!
 //! Author: Charles Barth
 //! Version: Spring 2025
-use godot::obj::WithBaseField;
-
-use crate::player::enums::player_events::PlayerEvents;
-use crate::player::{
-    enums::player_states::PlayerStates, player::Player, traits::player_state::PlayerState,
-};
+use crate::player::{player::Player, traits::player_state::PlayerState};
 
 /// The speed at which the player can move while crouching
 const CROUCH_SPEED: f32 = 75.0;
@@ -22,32 +17,21 @@ pub struct Crouch;
 impl PlayerState for Crouch {
     fn enter(_player: &mut Player) {}
 
-    /// This function is ran on every frame to flow from the current state (crouching) to the
-    /// next possible state.
+    /// Lets the player walk while crouching. Leaving the crouch is handled by the transition
+    /// table.
     ///
     /// # Arguments
     /// * `player` - The player object that is crouching
     fn update(player: &mut Player) {
-        let mut input_manager_unbound = player.get_input_manager();
-        let mut input_manager = input_manager_unbound.bind_mut();
+        Crouch::run(player);
+    }
 
-        if input_manager.check_for_player_event(PlayerEvents::Jump) && player.jump_available() {
-            player.set_state(PlayerStates::Jump);
-        } else if input_manager.fetch_player_event(PlayerEvents::Crouch) {
-            player.set_state(PlayerStates::CrouchEnd);
-        } else if !player.base().is_on_floor() {
-            player.set_state(PlayerStates::Fall);
-        } else if input_manager.fetch_player_event(PlayerEvents::Roll) {
-            if player.get_horizontal_movement() != 0.0 {
-                player.set_state(PlayerStates::Roll);
-            } else {
-                player.set_state(PlayerStates::CrouchEnd);
-            }
-        } else if input_manager.fetch_player_event(PlayerEvents::Sprint) {
-            player.set_state(PlayerStates::Run);
-        } else {
-            Crouch::run(player);
-        }
+    /// Puts the crouch walk animation back to its normal speed
+    ///
+    /// # Arguments
+    /// * `player` - The player that was crouching
+    fn exit(player: &mut Player) {
+        player.set_animation_speed(1.0);
     }
 }
 
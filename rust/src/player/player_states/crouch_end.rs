@@ -5,9 +5,7 @@
 //!
 //! Author: Charles Barth
 //! Version: Spring 2025
-use crate::player::{
-    enums::player_states::PlayerStates, player::Player, traits::player_state::PlayerState,
-};
+use crate::player::{player::Player, traits::player_state::PlayerState};
 
 // NOTE: This and most of the other states have not had documentation added
 // because Rust will automatically derive the documentation from the PlayerState trait.
@@ -27,13 +25,9 @@ impl PlayerState for CrouchEnd {
     fn enter(_player: &mut Player) {}
 
     /// This function is called every frame the player is in the CrouchEnd state.
-    /// It is responsible for updating the player's state and checking for state transitions.
+    /// Its state transitions are handled by the transition table.
     ///
     /// # Arguments
     /// * `player` - The player owner of the state.
-    fn update(player: &mut Player) {
-        if player.is_anim_finished() {
-            player.set_state(PlayerStates::Idle);
-        }
-    }
+    fn update(_player: &mut Player) {}
 }
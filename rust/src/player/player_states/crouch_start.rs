@@ -5,9 +5,7 @@
 //!
 //! Author: Charles Barth
 //! Version: Spring 2025
-use crate::player::{
-    enums::player_states::PlayerStates, player::Player, traits::player_state::PlayerState,
-};
+use crate::player::{player::Player, traits::player_state::PlayerState};
 
 #[derive(Clone, Copy)]
 pub struct CrouchStart;
@@ -15,9 +13,5 @@ pub struct CrouchStart;
 impl PlayerState for CrouchStart {
     fn enter(_player: &mut Player) {}
 
-    fn update(player: &mut Player) {
-        if player.is_anim_finished() {
-            player.set_state(PlayerStates::Crouch);
-        }
-    }
+    fn update(_player: &mut Player) {}
 }
@@ -8,9 +8,7 @@
 use godot::obj::WithBaseField;
 
 use crate::player::{
-    enums::{
-        player_events::PlayerEvents, player_states::PlayerStates, timeout_events::TimeoutEvents,
-    },
+    enums::{player_states::PlayerStates, timeout_events::TimeoutEvents},
     player::Player,
     traits::player_state::PlayerState,
 };
@@ -23,27 +21,29 @@ pub struct Fall;
 
 impl PlayerState for Fall {
     fn enter(player: &mut Player) {
-        if player.get_previous_state() != PlayerStates::Jump {
+        // Coyote time is only for walking off ledges, not for jumps or leaving walls
+        let previous_state = player.get_previous_state();
+        if previous_state != PlayerStates::Jump
+            && previous_state != PlayerStates::WallSlide
+            && previous_state != PlayerStates::WallJump
+            && previous_state != PlayerStates::LedgeGrab
+        {
             player.add_timeout_event(TimeoutEvents::CoyoteTime);
         }
     }
 
     fn update(player: &mut Player) {
-        let mut input_manager_unbound = player.get_input_manager();
-        let mut input_manager = input_manager_unbound.bind_mut();
+        Fall::run(player);
+        Fall::fall(player);
+    }
 
-        if player.base().is_on_floor() {
-            player.set_state(PlayerStates::Land);
-        } else if input_manager.check_for_player_event(PlayerEvents::Jump)
-            && player.jump_available()
-        {
-            player.set_state(PlayerStates::Jump);
-        } else if input_manager.fetch_player_event(PlayerEvents::Attack) {
-            player.set_state(PlayerStates::Attack);
-        } else {
-            Fall::run(player);
-            Fall::fall(player);
-        }
+    /// Clears the heavier falling gravity so it does not carry into the next state
+    ///
+    /// # Arguments
+    /// * `player` - The player that was falling
+    fn exit(player: &mut Player) {
+        let gravity = player.get_default_gravity();
+        player.set_gravity(gravity);
     }
 }
 
@@ -0,0 +1,41 @@
+//! hurt.rs
+//!
+//! This file contains the implementation of the Hurt state for the player.
+//! The Hurt state is entered from any other state when the player takes damage. The player loses
+//! control while they are stunned and is carried by the knockback of the hit.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use godot::obj::WithBaseField;
+
+use crate::player::{
+    enums::{player_states::PlayerStates, timeout_events::TimeoutEvents},
+    player::Player,
+    traits::player_state::PlayerState,
+};
+
+/// A struct representing the hurt state
+#[derive(Clone, Copy)]
+pub struct Hurt;
+
+impl PlayerState for Hurt {
+    fn enter(_player: &mut Player) {}
+
+    /// Once the hit-stun runs out the player regains control
+    ///
+    /// # Arguments
+    /// * `player` - The player that was hit
+    fn update(player: &mut Player) {
+        if player.is_timeout_event_active(TimeoutEvents::HitStun) {
+            return;
+        }
+
+        if !player.base().is_on_floor() {
+            player.set_state(PlayerStates::Fall);
+        } else if player.get_horizontal_movement() != 0.0 {
+            player.set_state(PlayerStates::Run);
+        } else {
+            player.set_state(PlayerStates::Idle);
+        }
+    }
+}
@@ -5,11 +5,8 @@
 //!
 //! Author: Charles Barth, Michael Imerman
 //! Version: Spring 2025
-use godot::obj::{GdMut, WithBaseField};
-
 use crate::player::{
-    enums::{force::Force, player_events::PlayerEvents, player_states::PlayerStates},
-    input_manager::InputManager,
+    enums::{force::Force, player_events::PlayerEvents},
     player::Player,
     traits::player_state::PlayerState,
 };
@@ -26,26 +23,8 @@ impl PlayerState for Jump {
     }
 
     fn update(player: &mut Player) {
-        let next_state: PlayerStates;
-        let mut input_manager_unbound = player.get_input_manager();
-        let mut input_manager = input_manager_unbound.bind_mut();
-
-        if !input_manager.check_for_player_event(PlayerEvents::Jump) {
-            next_state = PlayerStates::Fall;
-        } else if player.base().is_on_floor() {
-            next_state = PlayerStates::Land;
-        } else if input_manager.fetch_player_event(PlayerEvents::Attack) {
-            next_state = PlayerStates::Attack;
-        } else {
-            next_state = PlayerStates::Jump;
-        }
-
-        if next_state != PlayerStates::Jump {
-            Jump::exit(player, next_state);
-        } else {
-            Jump::run(player);
-            Jump::jump(player, input_manager);
-        }
+        Jump::run(player);
+        Jump::jump(player);
     }
 }
 
@@ -79,25 +58,19 @@ impl Jump {
         });
     }
 
-    /// Exits the jump state and enters the next state
-    /// This is used so there are fewer exit points in the update function
-    ///
-    /// # Arguments
-    /// * `player` - The player
-    /// * `next_state` - The next state to enter
-    fn exit(player: &mut Player, next_state: PlayerStates) {
-        player.set_state(next_state);
-    }
-
     /// This method allows the player to determine their jump height based on how long the jump button is held
     /// The longer the jump button is held, the higher the player will jump until the jump event is released or
     /// automatically expired after a predetermined amount of time
     ///
     /// # Arguments
     /// * `player` - The player
-    /// * `input_manager` - This is used to check if the jump button is pressed
-    fn jump(player: &mut Player, input_manager: GdMut<'_, InputManager>) {
-        if !input_manager.check_for_player_event(PlayerEvents::Jump) {
+    fn jump(player: &mut Player) {
+        let holding_jump = player
+            .get_input_manager()
+            .bind()
+            .check_for_player_event(PlayerEvents::Jump);
+
+        if !holding_jump {
             return;
         }
 
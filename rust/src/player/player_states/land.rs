@@ -3,14 +3,16 @@
 //! This file contains the implementation of the Land state for the player.
 //! The Land state is responsible for handling the player's landing animation
 //! and transitioning to other states based on player input and conditions.
+//! Landing too fast hurts the player and makes them stumble unless they roll as they land.
 //!
 //! Author: Charles Barth
 //! Version: Spring 2025
 use godot::{classes::AnimatedSprite2D, obj::WithBaseField};
 
 use crate::player::{
-    enums::{player_events::PlayerEvents, player_states::PlayerStates},
+    enums::{damage_type::DamageType, player_events::PlayerEvents, player_states::PlayerStates},
     player::Player,
+    player_states::roll::ROLL_STAMINA_COST,
     traits::player_state::PlayerState,
 };
 
@@ -21,21 +23,27 @@ impl PlayerState for Land {
     fn enter(player: &mut Player) {
         let mut dust = player.base().get_node_as::<AnimatedSprite2D>("Dust");
         dust.set_visible(true);
-    }
 
-    fn update(player: &mut Player) {
-        let horizontal_dir = player.get_horizontal_movement();
-        let mut input_manager_unbound = player.get_input_manager();
-        let input_manager = input_manager_unbound.bind_mut();
-
-        if input_manager.check_for_player_event(PlayerEvents::Jump) && player.jump_available() {
-            player.set_state(PlayerStates::Jump);
-        } else if !player.base().is_on_floor() {
-            player.set_state(PlayerStates::Fall);
-        } else if horizontal_dir != 0.0 {
-            player.set_state(PlayerStates::Run);
+        let impact_speed = player.get_previous_velocity().y;
+        if !player.is_hard_impact(impact_speed) {
+            return;
+        }
+
+        // Rolling as the player lands lets them keep moving instead of stumbling
+        let rolled = player.has_stamina(ROLL_STAMINA_COST)
+            && player
+                .get_input_manager()
+                .bind_mut()
+                .fetch_player_event(PlayerEvents::Roll);
+
+        player.take_impact_damage(impact_speed, DamageType::Fall);
+
+        if rolled {
+            player.set_state(PlayerStates::Roll);
         } else {
-            player.set_state(PlayerStates::Idle);
+            player.set_state(PlayerStates::Stumble);
         }
     }
+
+    fn update(_player: &mut Player) {}
 }
@@ -0,0 +1,73 @@
+//! ledge_climb.rs
+//!
+//! This file contains the implementation of the LedgeClimb state for the player.
+//! The LedgeClimb state plays the clamber animation and then places the player on top of the
+//! ledge they were hanging from.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use godot::{builtin::Vector2, obj::WithBaseField};
+
+use crate::player::{
+    enums::{force::Force, player_states::PlayerStates},
+    player::Player,
+    traits::player_state::PlayerState,
+};
+
+/// The offset from the ledge's corner to the player's position once they are standing on top of
+/// it, for a player facing right
+const STAND_OFFSET: Vector2 = Vector2::new(10.0, -24.0);
+
+#[derive(Clone, Copy)]
+pub struct LedgeClimb;
+
+impl PlayerState for LedgeClimb {
+    fn enter(player: &mut Player) {
+        LedgeClimb::hold(player);
+    }
+
+    /// The player stays on the ledge until the clamber animation finishes and then stands on top
+    ///
+    /// # Arguments
+    /// * `player` - The player climbing the ledge
+    fn update(player: &mut Player) {
+        if !player.is_anim_finished() {
+            LedgeClimb::hold(player);
+            return;
+        }
+
+        let direction = player.get_dir();
+        let stand_position =
+            player.get_ledge_point() + Vector2::new(STAND_OFFSET.x * direction, STAND_OFFSET.y);
+        player.base_mut().set_global_position(stand_position);
+
+        if player.get_horizontal_movement() != 0.0 {
+            player.set_state(PlayerStates::Run);
+        } else {
+            player.set_state(PlayerStates::Idle);
+        }
+    }
+
+    /// Restores gravity once the player is done climbing
+    ///
+    /// # Arguments
+    /// * `player` - The player that was climbing the ledge
+    fn exit(player: &mut Player) {
+        let gravity = player.get_default_gravity();
+        player.set_gravity(gravity);
+    }
+}
+
+impl LedgeClimb {
+    /// Holds the player still while they climb
+    ///
+    /// # Arguments
+    /// * `player` - The player climbing the ledge
+    fn hold(player: &mut Player) {
+        player.set_gravity(0.0);
+        player.add_force(Force::Stop {
+            horizontal: true,
+            vertical: true,
+        });
+    }
+}
@@ -0,0 +1,101 @@
+//! ledge_grab.rs
+//!
+//! This file contains the implementation of the LedgeGrab state for the player.
+//! The LedgeGrab state is entered when a falling player reaches the corner of a tile. The player
+//! hangs from the ledge and can climb up, drop down or jump off of it.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use godot::{builtin::Vector2, obj::WithBaseField};
+
+use crate::player::{
+    enums::{
+        force::Force, player_events::PlayerEvents, player_states::PlayerStates,
+        timeout_events::TimeoutEvents,
+    },
+    player::Player,
+    traits::player_state::PlayerState,
+};
+
+/// The offset from the ledge's corner to the player's position while hanging, for a player
+/// facing right
+const HANG_OFFSET: Vector2 = Vector2::new(-8.0, 20.0);
+/// How far the left stick has to be tilted to climb up or drop down
+const LEDGE_INPUT_THRESHOLD: f32 = 0.5;
+
+#[derive(Clone, Copy)]
+pub struct LedgeGrab;
+
+impl PlayerState for LedgeGrab {
+    /// The player catches the ledge and is moved to hang from its corner
+    ///
+    /// # Arguments
+    /// * `player` - The player grabbing the ledge
+    fn enter(player: &mut Player) {
+        let ledge_point = player.get_ledge_point();
+        let direction = player.get_dir();
+        let hang_position = ledge_point + Vector2::new(HANG_OFFSET.x * direction, HANG_OFFSET.y);
+
+        player.base_mut().set_global_position(hang_position);
+        LedgeGrab::hang(player);
+    }
+
+    fn update(player: &mut Player) {
+        let direction = player.get_dir();
+        let horizontal_dir = player.get_horizontal_movement();
+        let mut input_manager_unbound = player.get_input_manager();
+        let mut input_manager = input_manager_unbound.bind_mut();
+        let vertical_dir = input_manager.get_vertical_movement();
+
+        let holding_away = horizontal_dir != 0.0 && horizontal_dir.signum() != direction;
+        let jumped = input_manager.fetch_player_event(PlayerEvents::Jump);
+        let dropped = vertical_dir > LEDGE_INPUT_THRESHOLD
+            || input_manager.fetch_player_event(PlayerEvents::Crouch);
+        drop(input_manager);
+
+        if jumped && holding_away {
+            // Jumping while holding away from the ledge kicks off of the wall
+            player.set_wall_direction(-direction);
+            LedgeGrab::release(player, PlayerStates::WallJump);
+        } else if jumped || vertical_dir < -LEDGE_INPUT_THRESHOLD {
+            player.set_state(PlayerStates::LedgeClimb);
+        } else if dropped {
+            LedgeGrab::release(player, PlayerStates::Fall);
+        } else {
+            LedgeGrab::hang(player);
+        }
+    }
+
+    /// Restores gravity once the player is no longer hanging
+    ///
+    /// # Arguments
+    /// * `player` - The player that was hanging from the ledge
+    fn exit(player: &mut Player) {
+        let gravity = player.get_default_gravity();
+        player.set_gravity(gravity);
+    }
+}
+
+impl LedgeGrab {
+    /// Holds the player still on the ledge
+    ///
+    /// # Arguments
+    /// * `player` - The player hanging from the ledge
+    fn hang(player: &mut Player) {
+        player.set_gravity(0.0);
+        player.add_force(Force::Stop {
+            horizontal: true,
+            vertical: true,
+        });
+    }
+
+    /// Lets go of the ledge so the player does not grab it again right away
+    ///
+    /// # Arguments
+    /// * `player` - The player hanging from the ledge
+    /// * `next_state` - The state the player lets go into
+    fn release(player: &mut Player, next_state: PlayerStates) {
+        player.add_timeout_event(TimeoutEvents::LedgeRegrab);
+        player.set_state(next_state);
+    }
+}
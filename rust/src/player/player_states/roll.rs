@@ -8,12 +8,15 @@
 //! Version: Spring 2025
 use crate::player::{player::Player, traits::player_state::PlayerState};
 
+/// The amount of stamina used by a single roll
+pub const ROLL_STAMINA_COST: f64 = 20.0;
+
 #[derive(Clone, Copy)]
 pub struct Roll;
 
 impl PlayerState for Roll {
     fn enter(player: &mut Player) {
-        player.set_animation_speed(1.0);
+        player.drain_stamina(ROLL_STAMINA_COST);
     }
 
     fn update(player: &mut Player) {
@@ -22,4 +25,8 @@ impl PlayerState for Roll {
             player.set_state(previous_state);
         }
     }
+
+    fn can_enter(player: &Player) -> bool {
+        player.has_stamina(ROLL_STAMINA_COST)
+    }
 }
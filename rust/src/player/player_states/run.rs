@@ -6,13 +6,7 @@
 //!
 //! Author: Charles Barth, Michael Imerman
 //! Version: Spring 2025
-use godot::obj::WithBaseField;
-
-use crate::player::{
-    enums::{force::Force, player_events::PlayerEvents, player_states::PlayerStates},
-    player::Player,
-    traits::player_state::PlayerState,
-};
+use crate::player::{enums::force::Force, player::Player, traits::player_state::PlayerState};
 
 #[derive(Clone, Copy)]
 pub struct Run;
@@ -23,34 +17,15 @@ impl PlayerState for Run {
     }
 
     fn update(player: &mut Player) {
-        let horizontal_dir = player.get_horizontal_movement();
-        let mut input_manager_unbound = player.get_input_manager();
-        let mut input_manager = input_manager_unbound.bind_mut();
-        let mut next_state: PlayerStates = PlayerStates::Run;
-
-        if horizontal_dir == 0.0 {
-            next_state = PlayerStates::Idle;
-        } else if input_manager.check_for_player_event(PlayerEvents::Jump)
-            && player.jump_available()
-        {
-            next_state = PlayerStates::Jump;
-        } else if !player.base().is_on_floor() {
-            next_state = PlayerStates::Fall;
-        } else if input_manager.fetch_player_event(PlayerEvents::Crouch) {
-            next_state = PlayerStates::CrouchStart;
-        } else if input_manager.fetch_player_event(PlayerEvents::Roll) {
-            next_state = PlayerStates::Roll;
-        } else if input_manager.fetch_player_event(PlayerEvents::Sprint) {
-            next_state = PlayerStates::Sprint;
-        } else if input_manager.fetch_player_event(PlayerEvents::Attack) {
-            next_state = PlayerStates::Attack;
-        }
+        Run::run(player);
+    }
 
-        if next_state != PlayerStates::Run {
-            Run::exit(player, next_state);
-        } else {
-            Run::run(player);
-        }
+    /// The run animation is scaled by how far the joystick is pushed, so put it back to normal
+    ///
+    /// # Arguments
+    /// * `player` - The player that was running
+    fn exit(player: &mut Player) {
+        player.set_animation_speed(1.0);
     }
 }
 
@@ -88,13 +63,4 @@ impl Run {
 
         player.set_animation_speed(animation_speed);
     }
-
-    /// This is used to swap from the run state to the next state
-    ///
-    /// # Arguments
-    /// * `player` - The player
-    /// * `next_state` - The next state to transition to
-    fn exit(player: &mut Player, next_state: PlayerStates) {
-        player.set_state(next_state);
-    }
 }
@@ -6,9 +6,7 @@
 //!
 //! Author: Charles Barth
 //! Version: Spring 2025
-use crate::player::{
-    enums::player_states::PlayerStates, player::Player, traits::player_state::PlayerState,
-};
+use crate::player::{player::Player, traits::player_state::PlayerState};
 
 #[derive(Clone, Copy)]
 pub struct SlideCrouch;
@@ -16,9 +14,5 @@ pub struct SlideCrouch;
 impl PlayerState for SlideCrouch {
     fn enter(_player: &mut Player) {}
 
-    fn update(player: &mut Player) {
-        if player.is_anim_finished() {
-            player.set_state(PlayerStates::Crouch);
-        }
-    }
+    fn update(_player: &mut Player) {}
 }
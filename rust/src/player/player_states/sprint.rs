@@ -6,12 +6,10 @@
 //!
 //! Author: Charles Barth, Michael Imerman
 //! Version: Spring 2025
-use godot::obj::WithBaseField;
+use crate::player::{player::Player, traits::player_state::PlayerState};
 
-use crate::player::{
-    enums::player_events::PlayerEvents, enums::player_states::PlayerStates, player::Player,
-    traits::player_state::PlayerState,
-};
+/// The amount of stamina used per second of sprinting
+const SPRINT_STAMINA_COST: f64 = 25.0;
 
 #[derive(Clone, Copy)]
 pub struct Sprint;
@@ -22,33 +20,24 @@ impl PlayerState for Sprint {
     }
 
     fn update(player: &mut Player) {
-        let horizontal_dir = player.get_horizontal_movement();
-        let mut input_manager_unbound = player.get_input_manager();
-        let mut input_manager = input_manager_unbound.bind_mut();
-
-        if horizontal_dir.signum() != player.get_dir().signum() || horizontal_dir == 0.0 {
-            player.set_state(PlayerStates::Idle);
-        } else if input_manager.check_for_player_event(PlayerEvents::Jump)
-            && player.jump_available()
-        {
-            player.set_state(PlayerStates::Jump);
-        } else if !player.base().is_on_floor() {
-            player.set_state(PlayerStates::Fall);
-        // If player attempts to crouch while sprinting they slide into a crouch
-        } else if input_manager.fetch_player_event(PlayerEvents::Crouch) {
-            player.set_state(PlayerStates::SlideCrouch);
-        // If player attempts to roll while sprinting they slide instead
-        } else if input_manager.fetch_player_event(PlayerEvents::Roll) {
-            player.set_state(PlayerStates::Slide);
-        } else {
-            Sprint::run(player);
-        }
+        Sprint::run(player);
+    }
+
+    /// Slows the sped up sprint animation back down
+    ///
+    /// # Arguments
+    /// * `player` - The player that was sprinting
+    fn exit(player: &mut Player) {
+        player.set_animation_speed(1.0);
     }
 }
 
 impl Sprint {
     /// Applies horizontal velocity to the player so they can sprint
+    /// and drains the player's stamina
     fn run(player: &mut Player) {
+        player.drain_stamina(SPRINT_STAMINA_COST * player.get_delta());
+
         let dir = player.get_dir();
         let speed = player.get_run_speed() * 1.3;
         player.apply_horizontal_velocity(dir, speed);
@@ -0,0 +1,51 @@
+//! stumble.rs
+//!
+//! This file contains the implementation of the Stumble state for the player.
+//! The Stumble state is entered after a hard landing or being slammed into a wall and takes away
+//! the player's control until they recover.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use godot::obj::WithBaseField;
+
+use crate::player::{
+    enums::{force::Force, player_states::PlayerStates, timeout_events::TimeoutEvents},
+    player::Player,
+    traits::player_state::PlayerState,
+};
+
+/// A struct representing the stumble state
+#[derive(Clone, Copy)]
+pub struct Stumble;
+
+impl PlayerState for Stumble {
+    /// The player lost their footing, so they stop and can not act until they recover
+    ///
+    /// # Arguments
+    /// * `player` - The player that landed hard
+    fn enter(player: &mut Player) {
+        player.add_timeout_event(TimeoutEvents::Stumble);
+        player.add_force(Force::Stop {
+            horizontal: true,
+            vertical: false,
+        });
+    }
+
+    /// Once the stumble runs out the player regains control
+    ///
+    /// # Arguments
+    /// * `player` - The player that landed hard
+    fn update(player: &mut Player) {
+        if player.is_timeout_event_active(TimeoutEvents::Stumble) {
+            return;
+        }
+
+        if !player.base().is_on_floor() {
+            player.set_state(PlayerStates::Fall);
+        } else if player.get_horizontal_movement() != 0.0 {
+            player.set_state(PlayerStates::Run);
+        } else {
+            player.set_state(PlayerStates::Idle);
+        }
+    }
+}
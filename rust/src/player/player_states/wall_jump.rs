@@ -0,0 +1,87 @@
+//! wall_jump.rs
+//!
+//! This file contains the implementation of the WallJump state for the player.
+//! The WallJump state launches the player up and away from the wall they were sliding down.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use godot::{builtin::Vector2, obj::WithBaseField};
+
+use crate::player::{
+    enums::{force::Force, player_states::PlayerStates},
+    player::Player,
+    traits::player_state::PlayerState,
+};
+
+/// The horizontal speed the player is launched away from the wall with
+const WALL_JUMP_PUSH: f32 = 320.0;
+/// The fraction of the player's jump force used to launch them up the wall
+const WALL_JUMP_HEIGHT_SCALE: f32 = 0.9;
+/// The multiplier applied to the default gravity while rising from a wall jump
+const WALL_JUMP_GRAVITY_MULTIPLIER: f64 = 0.9;
+
+#[derive(Clone, Copy)]
+pub struct WallJump;
+
+impl PlayerState for WallJump {
+    /// Launches the player up and away from the wall
+    ///
+    /// # Arguments
+    /// * `player` - The player jumping off the wall
+    fn enter(player: &mut Player) {
+        let wall_direction = player.get_wall_direction();
+        player.set_dir(wall_direction);
+
+        player.add_force(Force::Stop {
+            horizontal: true,
+            vertical: true,
+        });
+        player.add_force(Force::Lunge {
+            impulse: Vector2::new(
+                wall_direction * WALL_JUMP_PUSH,
+                -player.get_jump_force() * WALL_JUMP_HEIGHT_SCALE,
+            ),
+        });
+    }
+
+    fn update(player: &mut Player) {
+        if player.base().is_on_floor() {
+            player.set_state(PlayerStates::Land);
+        } else if player.base().get_velocity().y >= 0.0 {
+            player.set_state(PlayerStates::Fall);
+        } else {
+            WallJump::rise(player);
+        }
+    }
+
+    /// Clears the lighter rising gravity of the wall jump
+    ///
+    /// # Arguments
+    /// * `player` - The player that was jumping off the wall
+    fn exit(player: &mut Player) {
+        let gravity = player.get_default_gravity();
+        player.set_gravity(gravity);
+    }
+}
+
+impl WallJump {
+    /// Lets the player steer away from the wall while rising. Steering back towards the wall is
+    /// ignored so the player can not climb a single wall by jumping into it over and over.
+    ///
+    /// # Arguments
+    /// * `player` - The player jumping off the wall
+    fn rise(player: &mut Player) {
+        let gravity = player.get_default_gravity() * WALL_JUMP_GRAVITY_MULTIPLIER;
+        player.set_gravity(gravity);
+
+        let horizontal_dir = player.get_horizontal_movement();
+        if horizontal_dir == 0.0 || horizontal_dir.signum() != player.get_wall_direction() {
+            return;
+        }
+
+        let speed = 900.0;
+        player.add_force(Force::AirRun {
+            acceleration: horizontal_dir * speed,
+        });
+    }
+}
@@ -0,0 +1,79 @@
+//! wall_slide.rs
+//!
+//! This file contains the implementation of the WallSlide state for the player.
+//! The WallSlide state is entered when the player holds towards a wall while falling. The player
+//! slides down the wall slowly and can jump off of it.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use godot::obj::WithBaseField;
+
+use crate::player::{
+    enums::{
+        force::Force, player_events::PlayerEvents, player_states::PlayerStates,
+        timeout_events::TimeoutEvents,
+    },
+    player::Player,
+    traits::player_state::PlayerState,
+};
+
+/// The multiplier applied to the default gravity while sliding down a wall
+const WALL_SLIDE_GRAVITY_MULTIPLIER: f64 = 0.25;
+
+#[derive(Clone, Copy)]
+pub struct WallSlide;
+
+impl PlayerState for WallSlide {
+    /// The player grabs onto the wall, stopping their fall
+    ///
+    /// # Arguments
+    /// * `player` - The player sliding down the wall
+    fn enter(player: &mut Player) {
+        player.update_wall_direction();
+        player.set_dir(-player.get_wall_direction());
+        player.add_force(Force::Stop {
+            horizontal: false,
+            vertical: true,
+        });
+    }
+
+    fn update(player: &mut Player) {
+        if player.base().is_on_floor() {
+            player.set_state(PlayerStates::Land);
+        } else if player
+            .get_input_manager()
+            .bind_mut()
+            .fetch_player_event(PlayerEvents::Jump)
+        {
+            player.set_state(PlayerStates::WallJump);
+        } else if !player.is_pushing_into_wall() {
+            // The player can still jump off the wall for a moment after letting go of it
+            player.add_timeout_event(TimeoutEvents::WallCoyoteTime);
+            player.set_state(PlayerStates::Fall);
+        } else {
+            WallSlide::slide(player);
+        }
+    }
+
+    /// Lets go of the wall, giving the player back their default gravity
+    ///
+    /// # Arguments
+    /// * `player` - The player that was sliding down the wall
+    fn exit(player: &mut Player) {
+        let gravity = player.get_default_gravity();
+        player.set_gravity(gravity);
+    }
+}
+
+impl WallSlide {
+    /// Lowers the player's gravity so they slide down the wall slowly
+    ///
+    /// # Arguments
+    /// * `player` - The player sliding down the wall
+    fn slide(player: &mut Player) {
+        player.update_wall_direction();
+
+        let gravity = player.get_default_gravity() * WALL_SLIDE_GRAVITY_MULTIPLIER;
+        player.set_gravity(gravity);
+    }
+}
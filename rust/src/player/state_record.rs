@@ -0,0 +1,64 @@
+//! state_record.rs
+//!
+//! This file defines the `StateRecord` struct which describes a single state transition a player
+//! made. Players keep a short history of these so the way a player got into a state can be traced
+//! while debugging.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use std::fmt;
+
+use godot::prelude::*;
+
+use super::enums::player_events::PlayerEvents;
+use super::enums::player_states::PlayerStates;
+
+/// A single state transition made by a player.
+#[derive(Clone)]
+pub struct StateRecord {
+    /// The physics frame the transition happened on.
+    pub frame: u64,
+    /// The state the player left.
+    pub from: PlayerStates,
+    /// The state the player entered.
+    pub to: PlayerStates,
+    /// The input event that triggered the transition, if any.
+    pub event: Option<PlayerEvents>,
+    /// The velocity of the player when the transition happened.
+    pub velocity: Vector2,
+}
+
+impl StateRecord {
+    /// Converts the record into a dictionary so it can be shown by GDScript.
+    ///
+    /// # Returns
+    /// * `Dictionary` - The record with the keys frame, from, to, event and velocity.
+    pub fn to_dictionary(&self) -> Dictionary {
+        let event = self
+            .event
+            .map(|event| format!("{:?}", event))
+            .unwrap_or_default();
+
+        let mut dictionary = Dictionary::new();
+        dictionary.set("frame", self.frame as i64);
+        dictionary.set("from", format!("{:?}", self.from));
+        dictionary.set("to", format!("{:?}", self.to));
+        dictionary.set("event", event);
+        dictionary.set("velocity", self.velocity);
+        dictionary
+    }
+}
+
+impl fmt::Display for StateRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {:?} -> {:?}", self.frame, self.from, self.to)?;
+        if let Some(event) = self.event {
+            write!(f, " on {:?}", event)?;
+        }
+        write!(
+            f,
+            " at velocity ({:.1}, {:.1})",
+            self.velocity.x, self.velocity.y
+        )
+    }
+}
@@ -0,0 +1,466 @@
+//! state_transitions.rs
+//!
+//! This file contains the player state transition table. Transitions that are shared between
+//! states, such as jumping, falling and attacking, are declared once here instead of being
+//! repeated in every state's update function. Each transition lists the states it can be taken
+//! from, the conditions that must all hold, its priority and the state it leads to.
+//!
+//! The table is checked for conflicting and unreachable transitions the first time it is used.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use godot::prelude::*;
+
+use super::enums::player_events::PlayerEvents;
+use super::enums::player_states::PlayerStates;
+use super::enums::transition_condition::TransitionCondition;
+use super::player::Player;
+
+use PlayerStates::*;
+use TransitionCondition::*;
+
+/// A transition from one of a set of states to another state.
+pub struct Transition {
+    /// The states the transition can be taken from
+    pub from: &'static [PlayerStates],
+    /// The conditions that must all hold for the transition to be taken, checked in order.
+    /// A transition with no conditions is always taken.
+    pub conditions: &'static [TransitionCondition],
+    /// Transitions with a higher priority are checked first
+    pub priority: u8,
+    /// The state the transition leads to
+    pub to: PlayerStates,
+}
+
+/// Every table driven transition the player can take.
+/// States that are not listed here, or that need extra logic when they exit, handle their
+/// transitions in their own update function.
+static TRANSITIONS: &[Transition] = &[
+    // Shared transitions
+    Transition {
+        from: &[Idle, Run, Crouch, Land, Sprint, Fall],
+        conditions: &[JumpPressed],
+        priority: 90,
+        to: Jump,
+    },
+    Transition {
+        from: &[Idle, Run, Crouch, Land, Sprint, Block],
+        conditions: &[Airborne],
+        priority: 80,
+        to: Fall,
+    },
+    Transition {
+        from: &[Idle, Run, Sprint, Fall, Jump],
+        conditions: &[AttackPressed],
+        priority: 30,
+        to: Attack,
+    },
+    Transition {
+        from: &[Idle, Run],
+        conditions: &[BlockHeld],
+        priority: 20,
+        to: Block,
+    },
+    Transition {
+        from: &[Idle, Run],
+        conditions: &[CrouchPressed],
+        priority: 60,
+        to: CrouchStart,
+    },
+    Transition {
+        from: &[Idle, Land],
+        conditions: &[HorizontalInput],
+        priority: 70,
+        to: Run,
+    },
+    // Idle
+    Transition {
+        from: &[Idle],
+        conditions: &[RollPressed],
+        priority: 50,
+        to: CrouchStart,
+    },
+    // Run
+    Transition {
+        from: &[Run],
+        conditions: &[NoHorizontalInput],
+        priority: 95,
+        to: Idle,
+    },
+    Transition {
+        from: &[Run],
+        conditions: &[HasRollStamina, RollPressed],
+        priority: 50,
+        to: Roll,
+    },
+    Transition {
+        from: &[Run],
+        conditions: &[NotFatigued, SprintPressed],
+        priority: 40,
+        to: Sprint,
+    },
+    // Sprint
+    Transition {
+        from: &[Sprint],
+        conditions: &[NotHoldingForward],
+        priority: 95,
+        to: Idle,
+    },
+    Transition {
+        from: &[Sprint],
+        conditions: &[Fatigued],
+        priority: 70,
+        to: Run,
+    },
+    Transition {
+        from: &[Sprint],
+        conditions: &[CrouchPressed],
+        priority: 60,
+        to: SlideCrouch,
+    },
+    Transition {
+        from: &[Sprint],
+        conditions: &[RollPressed],
+        priority: 50,
+        to: Slide,
+    },
+    // Crouch
+    Transition {
+        from: &[Crouch],
+        conditions: &[CrouchPressed],
+        priority: 85,
+        to: CrouchEnd,
+    },
+    Transition {
+        from: &[Crouch],
+        conditions: &[HorizontalInput, HasRollStamina, RollPressed],
+        priority: 50,
+        to: Roll,
+    },
+    Transition {
+        from: &[Crouch],
+        conditions: &[RollPressed],
+        priority: 45,
+        to: CrouchEnd,
+    },
+    Transition {
+        from: &[Crouch],
+        conditions: &[SprintPressed],
+        priority: 40,
+        to: Run,
+    },
+    // Land
+    Transition {
+        from: &[Land],
+        conditions: &[],
+        priority: 10,
+        to: Idle,
+    },
+    // Fall
+    Transition {
+        from: &[Fall],
+        conditions: &[Grounded],
+        priority: 100,
+        to: Land,
+    },
+    Transition {
+        from: &[Fall],
+        conditions: &[WallCoyoteJump],
+        priority: 95,
+        to: WallJump,
+    },
+    Transition {
+        from: &[Fall, Jump],
+        conditions: &[LedgeInReach],
+        priority: 70,
+        to: LedgeGrab,
+    },
+    Transition {
+        from: &[Fall],
+        conditions: &[PushingIntoWall],
+        priority: 60,
+        to: WallSlide,
+    },
+    // Jump
+    Transition {
+        from: &[Jump],
+        conditions: &[JumpReleased],
+        priority: 100,
+        to: Fall,
+    },
+    Transition {
+        from: &[Jump],
+        conditions: &[Grounded],
+        priority: 95,
+        to: Land,
+    },
+    // Block
+    Transition {
+        from: &[Block],
+        conditions: &[BlockReleased, HorizontalInput],
+        priority: 70,
+        to: Run,
+    },
+    Transition {
+        from: &[Block],
+        conditions: &[BlockReleased],
+        priority: 65,
+        to: Idle,
+    },
+    Transition {
+        from: &[Block],
+        conditions: &[Fatigued, HorizontalInput],
+        priority: 60,
+        to: Run,
+    },
+    Transition {
+        from: &[Block],
+        conditions: &[Fatigued],
+        priority: 55,
+        to: Idle,
+    },
+    // Animation driven states
+    Transition {
+        from: &[CrouchStart, SlideCrouch],
+        conditions: &[AnimationFinished],
+        priority: 10,
+        to: Crouch,
+    },
+    Transition {
+        from: &[CrouchEnd],
+        conditions: &[AnimationFinished],
+        priority: 10,
+        to: Idle,
+    },
+];
+
+impl Transition {
+    /// Gets the input event that triggers the transition, if any of its conditions check one.
+    ///
+    /// # Returns
+    /// * `Option<PlayerEvents>` - The event that triggers the transition.
+    pub fn trigger(&self) -> Option<PlayerEvents> {
+        self.conditions
+            .iter()
+            .find_map(|condition| condition.event())
+    }
+}
+
+/// The transitions of each state, sorted from highest to lowest priority
+static TRANSITIONS_BY_STATE: OnceLock<HashMap<PlayerStates, Vec<&'static Transition>>> =
+    OnceLock::new();
+
+/// Evaluates the player state transition table.
+pub struct StateTransitions;
+
+impl StateTransitions {
+    /// Finds the first transition out of a state whose conditions all hold.
+    ///
+    /// # Arguments
+    /// * `state` - The state the player is in.
+    /// * `player` - The player to check the conditions for.
+    ///
+    /// # Returns
+    /// * `Option<&Transition>` - The transition to take, if any.
+    pub fn next_transition(
+        state: PlayerStates,
+        player: &mut Player,
+    ) -> Option<&'static Transition> {
+        let transitions = TRANSITIONS_BY_STATE.get_or_init(Self::build).get(&state)?;
+
+        transitions
+            .iter()
+            .find(|transition| {
+                transition
+                    .conditions
+                    .iter()
+                    .all(|condition| condition.check(player))
+            })
+            .copied()
+    }
+
+    /// Groups the transitions by the states they can be taken from and sorts them by priority.
+    /// The table is validated while it is built.
+    ///
+    /// # Returns
+    /// * `HashMap<PlayerStates, Vec<&Transition>>` - The transitions out of each state.
+    fn build() -> HashMap<PlayerStates, Vec<&'static Transition>> {
+        let by_state = Self::group(TRANSITIONS);
+        for (state, transitions) in by_state.iter() {
+            for problem in Self::validate(*state, transitions) {
+                godot_error!("{}", problem);
+            }
+        }
+
+        by_state
+    }
+
+    /// Groups transitions by the states they can be taken from and sorts them by priority.
+    ///
+    /// # Arguments
+    /// * `table` - The transitions to group.
+    ///
+    /// # Returns
+    /// * `HashMap<PlayerStates, Vec<&Transition>>` - The transitions out of each state.
+    fn group(table: &'static [Transition]) -> HashMap<PlayerStates, Vec<&'static Transition>> {
+        let mut by_state: HashMap<PlayerStates, Vec<&'static Transition>> = HashMap::new();
+        for transition in table.iter() {
+            for state in transition.from {
+                by_state.entry(*state).or_default().push(transition);
+            }
+        }
+
+        for transitions in by_state.values_mut() {
+            transitions.sort_by(|a, b| b.priority.cmp(&a.priority));
+        }
+
+        by_state
+    }
+
+    /// Finds transitions out of a state that conflict with each other or can never be taken.
+    /// Two transitions conflict when they share a priority, since the order they are checked in
+    /// would be arbitrary. A transition can never be taken when it leads back to the same state,
+    /// when a transition with the same conditions is checked before it, or when a transition with
+    /// no conditions is checked before it.
+    ///
+    /// # Arguments
+    /// * `state` - The state the transitions are taken from.
+    /// * `transitions` - The transitions out of the state, sorted by priority.
+    ///
+    /// # Returns
+    /// * `Vec<String>` - A description of every problem found.
+    fn validate(state: PlayerStates, transitions: &[&Transition]) -> Vec<String> {
+        let mut problems = Vec::new();
+        for (index, transition) in transitions.iter().enumerate() {
+            if transition.to == state {
+                problems.push(format!(
+                    "Unreachable transition {:?} -> {:?}: leads back to the same state",
+                    state, transition.to
+                ));
+            }
+
+            for earlier in transitions[..index].iter() {
+                if earlier.priority == transition.priority {
+                    problems.push(format!(
+                        "Conflicting transitions {:?} -> {:?} and {:?} -> {:?}: both have priority {}",
+                        state, earlier.to, state, transition.to, transition.priority
+                    ));
+                } else if earlier.conditions.is_empty()
+                    || earlier.conditions == transition.conditions
+                {
+                    problems.push(format!(
+                        "Unreachable transition {:?} -> {:?}: always preceded by {:?} -> {:?}",
+                        state, transition.to, state, earlier.to
+                    ));
+                }
+            }
+        }
+
+        problems
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A table with one problem of every kind
+    static BROKEN: &[Transition] = &[
+        Transition {
+            from: &[Idle],
+            conditions: &[JumpPressed],
+            priority: 50,
+            to: Jump,
+        },
+        Transition {
+            from: &[Idle],
+            conditions: &[AttackPressed],
+            priority: 50,
+            to: Attack,
+        },
+        Transition {
+            from: &[Run],
+            conditions: &[],
+            priority: 50,
+            to: Idle,
+        },
+        Transition {
+            from: &[Run],
+            conditions: &[Airborne],
+            priority: 40,
+            to: Fall,
+        },
+        Transition {
+            from: &[Crouch],
+            conditions: &[CrouchPressed],
+            priority: 50,
+            to: CrouchEnd,
+        },
+        Transition {
+            from: &[Crouch],
+            conditions: &[CrouchPressed],
+            priority: 40,
+            to: Idle,
+        },
+        Transition {
+            from: &[Fall],
+            conditions: &[Grounded],
+            priority: 50,
+            to: Fall,
+        },
+    ];
+
+    /// Gets the problems found with the transitions out of a state of a table
+    fn problems(table: &'static [Transition], state: PlayerStates) -> Vec<String> {
+        let by_state = StateTransitions::group(table);
+        StateTransitions::validate(state, &by_state[&state])
+    }
+
+    #[test]
+    fn player_table_is_valid() {
+        for (state, transitions) in StateTransitions::group(TRANSITIONS).iter() {
+            let problems = StateTransitions::validate(*state, transitions);
+            assert!(problems.is_empty(), "{:?}", problems);
+        }
+    }
+
+    #[test]
+    fn transitions_are_sorted_by_priority() {
+        for transitions in StateTransitions::group(TRANSITIONS).values() {
+            assert!(transitions
+                .windows(2)
+                .all(|pair| pair[0].priority >= pair[1].priority));
+        }
+    }
+
+    #[test]
+    fn shared_priority_is_a_conflict() {
+        let problems = problems(BROKEN, Idle);
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].starts_with("Conflicting transitions"));
+    }
+
+    #[test]
+    fn transition_after_unconditional_transition_is_unreachable() {
+        let problems = problems(BROKEN, Run);
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("always preceded by Run -> Idle"));
+    }
+
+    #[test]
+    fn transition_with_repeated_conditions_is_unreachable() {
+        let problems = problems(BROKEN, Crouch);
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("always preceded by Crouch -> CrouchEnd"));
+    }
+
+    #[test]
+    fn transition_to_same_state_is_unreachable() {
+        let problems = problems(BROKEN, Fall);
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("leads back to the same state"));
+    }
+}
@@ -0,0 +1,86 @@
+//! input_source.rs
+//!
+//! This file defines the InputSource trait. The input manager reads every stick, trigger and
+//! button of its player through an input source, so a player can be driven by a controller, a
+//! script, a recorded replay or a remote peer without the rest of the game knowing the difference.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use godot::{classes::InputEvent, prelude::*};
+
+use crate::player::{
+    enums::{input_action::InputAction, metal_type::MetalType},
+    input_frame::InputFrame,
+};
+
+/// This trait defines where a player's input comes from.
+pub trait InputSource {
+    /// Checks if an input event from Godot belongs to this source. Only sources that read
+    /// hardware accept events. The source can also keep track of anything it needs from the
+    /// event, such as which device the player last aimed with.
+    ///
+    /// # Arguments
+    /// * `event` - The input event that was detected.
+    ///
+    /// # Returns
+    /// * `bool` - True if the event should be handled as this player's input.
+    fn accept_event(&mut self, _event: &Gd<InputEvent>) -> bool {
+        false
+    }
+
+    /// Moves the source on by one physics frame.
+    /// Sources that do not get their buttons from Godot input events return the buttons that
+    /// were pressed or released during the frame here.
+    ///
+    /// # Returns
+    /// * `Vec<(InputAction, bool)>` - The actions of the buttons and whether they were pressed.
+    fn advance(&mut self) -> Vec<(InputAction, bool)> {
+        Vec::new()
+    }
+
+    /// Queues a frame of input. This is how sources that are fed from outside the game, such as
+    /// scripts and remote peers, are given their input. Other sources ignore it.
+    ///
+    /// # Arguments
+    /// * `frame` - The frame to queue.
+    fn push_frame(&mut self, _frame: InputFrame) {}
+
+    /// Gets how far the player is moving left or right.
+    ///
+    /// # Returns
+    /// * `f32` - The horizontal movement from -1 to 1.
+    fn horizontal_movement(&self) -> f32;
+
+    /// Gets how far the player is holding up or down. Up is negative to match Godot's y axis.
+    ///
+    /// # Returns
+    /// * `f32` - The vertical movement from -1 to 1.
+    fn vertical_movement(&self) -> f32;
+
+    /// Gets the direction the player is aiming in.
+    ///
+    /// # Arguments
+    /// * `origin` - The global position the player aims from.
+    /// * `cursor` - The global position of the mouse cursor.
+    ///
+    /// # Returns
+    /// * `Vector2` - The aim direction. Its length is the strength of the aim, up to 1.
+    fn aim_direction(&self, origin: Vector2, cursor: Vector2) -> Vector2;
+
+    /// Gets how hard the player is burning a metal that pushes or pulls.
+    ///
+    /// # Arguments
+    /// * `metal` - The metal being burned.
+    ///
+    /// # Returns
+    /// * `f32` - How hard the metal is burned from 0 to 1.
+    fn burn_strength(&self, metal: MetalType) -> f32;
+
+    /// Checks if the source reads the keyboard and mouse.
+    ///
+    /// # Returns
+    /// * `bool` - True if the player plays with the keyboard and mouse.
+    fn is_keyboard(&self) -> bool {
+        false
+    }
+}
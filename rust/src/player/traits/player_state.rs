@@ -23,4 +23,24 @@ pub trait PlayerState {
     /// # Arguments
     /// * `player` - The player owner of the state.
     fn update(player: &mut Player);
+
+    /// This function is called when the player leaves the state, no matter what caused them to
+    /// leave it. It is responsible for releasing anything the state acquired, such as the hitbox,
+    /// a gravity override or a changed animation speed.
+    ///
+    /// # Arguments
+    /// * `player` - The player owner of the state.
+    fn exit(_player: &mut Player) {}
+
+    /// This function is called before the player enters the state.
+    /// States that can only be entered under certain conditions refuse the transition here.
+    ///
+    /// # Arguments
+    /// * `player` - The player owner of the state.
+    ///
+    /// # Returns
+    /// * `bool` - True if the player can enter the state.
+    fn can_enter(_player: &Player) -> bool {
+        true
+    }
 }
@@ -0,0 +1,39 @@
+//! player_stats.rs
+//!
+//! This file contains the PlayerStats struct which records how a player has performed over the
+//! course of a game. The Game keeps one record per player id.
+//!
+//! Author: Charles Barth
+//! Version: Spring 2025
+use godot::prelude::*;
+
+/// The stats recorded for a single player over a game.
+#[derive(Clone, Debug, Default)]
+pub struct PlayerStats {
+    /// The number of players this player landed the killing blow on.
+    pub eliminations: i32,
+    /// The number of eliminations this player helped with without landing the killing blow.
+    pub assists: i32,
+    /// The number of times this player was eliminated.
+    pub deaths: i32,
+    /// The total damage this player dealt to other players.
+    pub damage_dealt: f64,
+    /// The total damage this player took.
+    pub damage_taken: f64,
+}
+
+impl PlayerStats {
+    /// Converts the stats into a dictionary so they can be read from GDScript.
+    ///
+    /// # Returns
+    /// * `Dictionary` - The stats keyed by their names.
+    pub fn to_dictionary(&self) -> Dictionary {
+        let mut dictionary = Dictionary::new();
+        dictionary.set("eliminations", self.eliminations);
+        dictionary.set("assists", self.assists);
+        dictionary.set("deaths", self.deaths);
+        dictionary.set("damage_dealt", self.damage_dealt);
+        dictionary.set("damage_taken", self.damage_taken);
+        dictionary
+    }
+}
@@ -6,8 +6,17 @@
 //!
 //! Author: Trinity Pittman, Charles Barth
 //! Version: Spring 2025
+use std::collections::HashMap;
+use std::time::Duration;
+
 use godot::{classes::Object, prelude::*};
 
+use crate::player::analog_profile::AnalogProfile;
+use crate::player::enums::analog_input::AnalogInput;
+use crate::player::enums::metal_type::{BurnType, MetalType};
+use crate::player::enums::player_events::PlayerEvents;
+use crate::player::enums::response_curve::ResponseCurve;
+
 /// This is a struct for all the adjustable settings in the game
 #[derive(GodotClass)]
 #[class(base=Object)]
@@ -22,6 +31,10 @@ pub struct Settings {
     pub map: MapSettings,
     /// The general settings.
     pub general: GeneralSettings,
+    /// The metal settings.
+    pub metals: MetalSettings,
+    /// The input settings.
+    pub input: InputSettings,
     /// The game mode
     pub game_mode: GameMode,
 }
@@ -42,6 +55,8 @@ impl IObject for Settings {
             environment: EnvironmentSettings::default(),
             map: MapSettings::default(),
             general: GeneralSettings::default(),
+            metals: MetalSettings::default(),
+            input: InputSettings::default(),
             game_mode: GameMode::default(),
         }
     }
@@ -90,6 +105,202 @@ impl Settings {
         self.general.debug_mode
     }
 
+    /// Gets the range that a metal can sense metal objects from.
+    /// Metals that do not interact with metal objects have no limit.
+    /// # Arguments
+    /// * `metal` - The metal being burned.
+    /// * `burn_type` - Whether the metal is being burned or low burned.
+    ///
+    /// # Returns
+    /// * (f32) - The range in pixels.
+    pub fn get_metal_range(&self, metal: MetalType, burn_type: BurnType) -> f32 {
+        match (metal, burn_type) {
+            (MetalType::Steel, BurnType::Burn) => self.metals.steel_burn_range,
+            (MetalType::Steel, BurnType::LowBurn) => self.metals.steel_low_burn_range,
+            (MetalType::Iron, BurnType::Burn) => self.metals.iron_burn_range,
+            (MetalType::Iron, BurnType::LowBurn) => self.metals.iron_low_burn_range,
+            _ => f32::INFINITY,
+        }
+    }
+
+    /// Sets the range that steel or iron can sense metal objects from.
+    /// # Arguments
+    /// * `metal` - "steel" or "iron".
+    /// * `low_burn` - True to set the low burn range, false to set the burn range.
+    /// * `range` - The range in pixels.
+    #[func]
+    pub fn set_metal_range(&mut self, metal: String, low_burn: bool, range: f32) {
+        if range <= 0.0 {
+            godot_error!("Metal range {} must be greater than 0", range);
+            return;
+        }
+
+        let metals = &mut self.metals;
+        let setting = match (MetalType::from_string(&metal), low_burn) {
+            (Some(MetalType::Steel), false) => &mut metals.steel_burn_range,
+            (Some(MetalType::Steel), true) => &mut metals.steel_low_burn_range,
+            (Some(MetalType::Iron), false) => &mut metals.iron_burn_range,
+            (Some(MetalType::Iron), true) => &mut metals.iron_low_burn_range,
+            _ => {
+                godot_error!("{} does not have a range", metal);
+                return;
+            }
+        };
+        *setting = range;
+    }
+
+    /// Gets whether walls block the metal lines of steel and iron.
+    /// # Returns
+    /// * (bool) - Whether line of sight is required.
+    #[func]
+    pub fn is_line_of_sight_enabled(&self) -> bool {
+        self.metals.line_of_sight
+    }
+
+    /// Sets whether walls block the metal lines of steel and iron.
+    /// # Arguments
+    /// * `line_of_sight` - Whether line of sight is required.
+    #[func]
+    pub fn set_line_of_sight_enabled(&mut self, line_of_sight: bool) {
+        self.metals.line_of_sight = line_of_sight;
+    }
+
+    /// Gets the fraction of push strength left at the edge of the burn range.
+    /// # Returns
+    /// * (f32) - The push strength at max range.
+    #[func]
+    pub fn get_min_push_falloff(&self) -> f32 {
+        self.metals.min_push_falloff
+    }
+
+    /// Sets the fraction of push strength left at the edge of the burn range.
+    /// # Arguments
+    /// * `falloff` - The push strength at max range, from 0 to 1.
+    #[func]
+    pub fn set_min_push_falloff(&mut self, falloff: f32) {
+        if !(0.0..=1.0).contains(&falloff) {
+            godot_error!("Push falloff {} is not between 0 and 1", falloff);
+            return;
+        }
+        self.metals.min_push_falloff = falloff;
+    }
+
+    /// Gets how long a press of a player event is buffered for.
+    /// # Arguments
+    /// * `event` - The player event.
+    ///
+    /// # Returns
+    /// * (Option<Duration>) - How long the press is buffered for, None if it lasts until the
+    ///   button is released.
+    pub fn get_input_buffer(&self, event: PlayerEvents) -> Option<Duration> {
+        match self.input.buffer_windows.get(&event) {
+            Some(window) => *window,
+            None => event.default_buffer_window(),
+        }
+    }
+
+    /// Gets how long a press of a player event is buffered for in milliseconds.
+    /// # Arguments
+    /// * `event` - The player event.
+    ///
+    /// # Returns
+    /// * (i64) - The buffer window in milliseconds, -1 if it lasts until the button is released.
+    #[func]
+    pub fn get_input_buffer_ms(&self, event: PlayerEvents) -> i64 {
+        self.get_input_buffer(event)
+            .map_or(-1, |window| window.as_millis() as i64)
+    }
+
+    /// Sets how long a press of a player event is buffered for in milliseconds.
+    /// # Arguments
+    /// * `event` - The player event.
+    /// * `ms` - The buffer window in milliseconds, negative to last until the button is released.
+    #[func]
+    pub fn set_input_buffer_ms(&mut self, event: PlayerEvents, ms: i64) {
+        let window = (ms >= 0).then(|| Duration::from_millis(ms as u64));
+        self.input.buffer_windows.insert(event, window);
+    }
+
+    /// Gets the analog settings of the player using a device.
+    /// # Arguments
+    /// * `device_id` - The device the player plays with.
+    ///
+    /// # Returns
+    /// * (AnalogProfile) - The player's analog settings, the defaults if none have been set.
+    pub fn get_analog_profile(&self, device_id: i32) -> AnalogProfile {
+        self.input
+            .analog_profiles
+            .get(&device_id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Sets the deadzones of a stick or the triggers for the player using a device.
+    /// # Arguments
+    /// * `device_id` - The device the player plays with.
+    /// * `input` - "movement", "aim" or "triggers".
+    /// * `inner` - How far the input has to be pushed before it counts, from 0 to 1.
+    /// * `outer` - How far the input has to be pushed to count as fully pushed, from 0 to 1.
+    #[func]
+    pub fn set_analog_deadzones(&mut self, device_id: i32, input: String, inner: f32, outer: f32) {
+        let Some(input) = AnalogInput::from_string(&input) else {
+            godot_error!("{} is not a stick or trigger", input);
+            return;
+        };
+        if !(0.0..1.0).contains(&inner) || outer <= inner || outer > 1.0 {
+            godot_error!("Deadzones {} to {} are not between 0 and 1", inner, outer);
+            return;
+        }
+
+        let profile = self.input.analog_profiles.entry(device_id).or_default();
+        let response = profile.response_mut(input);
+        response.inner_deadzone = inner;
+        response.outer_deadzone = outer;
+    }
+
+    /// Sets the response curve of a stick or the triggers for the player using a device.
+    /// # Arguments
+    /// * `device_id` - The device the player plays with.
+    /// * `input` - "movement", "aim" or "triggers".
+    /// * `curve` - "linear", "quadratic" or "custom".
+    /// * `exponent` - The power the input is raised to by the custom curve.
+    #[func]
+    pub fn set_analog_curve(
+        &mut self,
+        device_id: i32,
+        input: String,
+        curve: String,
+        exponent: f32,
+    ) {
+        let Some(input) = AnalogInput::from_string(&input) else {
+            godot_error!("{} is not a stick or trigger", input);
+            return;
+        };
+        let Some(curve) = ResponseCurve::from_string(&curve, exponent) else {
+            godot_error!(
+                "{} with exponent {} is not a response curve",
+                curve,
+                exponent
+            );
+            return;
+        };
+
+        let profile = self.input.analog_profiles.entry(device_id).or_default();
+        profile.response_mut(input).curve = curve;
+    }
+
+    /// Sets whether aiming is flipped for the player using a device.
+    /// # Arguments
+    /// * `device_id` - The device the player plays with.
+    /// * `invert_x` - True to flip aiming left and right.
+    /// * `invert_y` - True to flip aiming up and down.
+    #[func]
+    pub fn set_aim_inverted(&mut self, device_id: i32, invert_x: bool, invert_y: bool) {
+        let profile = self.input.analog_profiles.entry(device_id).or_default();
+        profile.invert_aim_x = invert_x;
+        profile.invert_aim_y = invert_y;
+    }
+
     /// Gets the game mode.
     /// # Returns
     /// * A string representing the game mode, defaults to "Last Player Standing".
@@ -230,6 +441,50 @@ impl Default for GeneralSettings {
     }
 }
 
+#[derive(Clone)]
+/// This is a struct for the metal settings.
+pub struct MetalSettings {
+    /// The range steel can push from while burning.
+    pub steel_burn_range: f32,
+    /// The range steel lines are shown from while low burning.
+    pub steel_low_burn_range: f32,
+    /// The range iron can pull from while burning.
+    pub iron_burn_range: f32,
+    /// The range iron lines are shown from while low burning.
+    pub iron_low_burn_range: f32,
+    /// Whether walls block metal lines.
+    pub line_of_sight: bool,
+    /// The fraction of push strength left at the edge of the burn range.
+    pub min_push_falloff: f32,
+}
+
+///  Default methods for the Metal Settings
+impl Default for MetalSettings {
+    /// Default method for the Metal Settings
+    /// # Returns
+    /// * MetalSettings with default values
+    fn default() -> Self {
+        Self {
+            steel_burn_range: 450.0,
+            steel_low_burn_range: 320.0,
+            iron_burn_range: 400.0,
+            iron_low_burn_range: 320.0,
+            line_of_sight: true,
+            min_push_falloff: 0.3,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+/// This is a struct for the input settings.
+pub struct InputSettings {
+    /// How long presses of each player event are buffered for, overriding the event's default.
+    /// None means presses last until the button is released.
+    pub buffer_windows: HashMap<PlayerEvents, Option<Duration>>,
+    /// The analog settings of each player, by the device they play with.
+    pub analog_profiles: HashMap<i32, AnalogProfile>,
+}
+
 /// Represents the game mode and whether this game is team or solo based.
 #[derive(Clone)]
 pub struct GameMode {